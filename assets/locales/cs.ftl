@@ -0,0 +1,139 @@
+# Czech string table. Missing keys fall back to English.
+
+## Languages
+language-en = English
+language-cs = Čeština
+
+## Common
+common-return = <- Zpět
+common-return-main-menu = <- Hlavní menu
+
+## Intro
+intro-skip = Stiskněte libovolnou klávesu pro přeskočení, podržte pro přeskočení intra
+
+## Main menu
+main-menu-continue = POKRAČOVAT
+main-menu-new-game = NOVÁ HRA
+main-menu-load-game = NAČÍST HRU
+main-menu-settings = NASTAVENÍ
+main-menu-additional-content = DALŠÍ OBSAH
+main-menu-credits = AUTOŘI
+main-menu-quit-game = UKONČIT HRU
+main-menu-load-game-tooltip = Zatím nejsou žádné uložené hry.
+
+## Character creator
+creator-title = Tvorba postavy
+creator-confirm = Potvrdit
+creator-gender = Pohlaví
+creator-gender-female = Žena
+creator-gender-male = Muž
+creator-body = Tělo
+creator-body-1 = Tělo 1
+creator-body-2 = Tělo 2
+creator-body-3 = Tělo 3
+creator-color = Barva
+creator-color-red = Červená
+creator-color-blue = Modrá
+creator-hair = Vlasy
+creator-hair-short = Krátké
+creator-hair-bun = Drdol
+creator-hair-long = Dlouhé
+creator-hair-ponytail = Culík
+creator-beard = Vousy
+creator-beard-none = Žádné
+
+## Settings
+settings-title = Nastavení
+settings-language = Jazyk
+settings-display = Zobrazení
+settings-display-windowed = Okno
+settings-display-fullscreen = Celá obrazovka
+settings-language-tooltip = Jazyk všech textů ve hře.
+settings-display-tooltip = Hrát v okně nebo na celou obrazovku.
+settings-screen-effects = Obrazové efekty
+settings-screen-effects-tooltip = Síla řádkování, zkreslení, zrnitosti a poruch obrazu.
+settings-screen-effects-off = Vypnuto
+settings-screen-effects-subtle = Jemné
+settings-screen-effects-full = Plné
+settings-reduced-motion = Omezit pohyb
+settings-reduced-motion-tooltip = Vypne otřesy kamery, pohupování a kývání.
+settings-off = Vypnuto
+settings-on = Zapnuto
+settings-audio = Zvuk
+settings-volume-master = Celková
+settings-volume-music = Hudba
+settings-volume-sfx = Efekty
+settings-volume-ui = Rozhraní
+settings-volume-ambience = Prostředí
+settings-volume-voice = Hlasy
+settings-volume-tooltip = Hlasitost kategorie zvuků, celková hlasitost ovlivňuje všechny.
+settings-mute = Ztlumit
+settings-mute-tooltip = Umlčí kategorii zvuků bez změny její hlasitosti.
+settings-description-placeholder = Najeďte na nastavení pro zobrazení popisu.
+
+## Additional content
+content-title = Další obsah
+content-empty = Nejsou nainstalovány žádné balíčky obsahu. Vložte je do složky content vedle hry.
+content-restart = Změny se projeví po restartu hry.
+content-pack-enabled = Zapnuto
+content-pack-disabled = Vypnuto
+content-pack-invalid = Neplatný
+content-error-archive = Archivy zatím nejsou podporovány, rozbalte balíček do složky.
+content-error-no-manifest = Balíček nemá manifest pack.toml.
+content-error-missing-field = V manifestu chybí název, verze nebo autor.
+content-error-version = Verze musí vypadat jako 1.0.0.
+content-error-thumbnail = Soubor s náhledem neexistuje.
+
+## Loading
+loading-title = NAČÍTÁNÍ
+loading-tip-1 = Tip: Podržením klávesy přeskočíte celé intro.
+loading-tip-2 = Tip: Balíčky obsahu mohou nahradit jakýkoliv soubor hry.
+loading-tip-3 = Tip: Všechna menu lze ovládat gamepadem.
+loading-tip-4 = Tip: Najeďte na nastavení a uvidíte jeho popis.
+loading-error-title = Některé soubory se nepodařilo načíst
+loading-continue = Přesto pokračovat
+
+## Console
+console-placeholder = Zadejte příkaz, help vypíše všechny příkazy
+
+## Tuning
+tuning-title = Ladění
+tuning-save = Uložit
+tuning-prone-speed = Rychlost v lehu
+tuning-crouch-speed = Rychlost v podřepu
+tuning-ads-speed = Rychlost při míření
+tuning-base-speed = Rychlost chůze
+tuning-running-speed = Rychlost běhu
+tuning-sprinting-speed = Rychlost sprintu
+tuning-strafing-multiplier = Násobič úkroku
+tuning-backwards-multiplier = Násobič couvání
+tuning-player-acceleration = Zrychlení hráče
+tuning-controller-acceleration = Zrychlení ovladače
+tuning-controller-damping = Tlumení ovladače
+tuning-controller-jump-impulse = Síla skoku
+tuning-controller-acceleration-multiplier = Násobič vstupu ovladače
+tuning-flicker-frequency = Frekvence blikání
+tuning-flicker-intensity-min = Min. intenzita blikání
+tuning-flicker-intensity-max = Max. intenzita blikání
+tuning-flicker-threshold-min = Min. práh blikání
+tuning-flicker-threshold-max = Max. práh blikání
+tuning-flicker-smoothing = Vyhlazení blikání
+tuning-flicker-burst-chance = Šance série blikání
+tuning-flicker-burst-count = Délka série blikání
+tuning-flicker-burst-interval = Interval série blikání
+tuning-effect-scanlines = Řádkování
+tuning-effect-distortion = Soudkovité zkreslení
+tuning-effect-aberration = Chromatická aberace
+tuning-effect-vignette = Vinětace
+tuning-effect-grain = Zrnitost
+tuning-effect-glitch = Porucha obrazu
+
+## Dialogs
+dialog-confirm = Potvrdit
+dialog-cancel = Zrušit
+dialog-quit-title = Ukončit hru
+dialog-quit-message = Opravdu chcete ukončit hru?
+dialog-discard-title = Zahodit změny
+dialog-discard-message = Změny vaší postavy budou ztraceny.
+dialog-overwrite-title = Přepsat uložení
+dialog-overwrite-message = Vybraná pozice bude přepsána.
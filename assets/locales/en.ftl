@@ -0,0 +1,140 @@
+# English string table. This is the fallback language,
+# every key used in the game must be present here.
+
+## Languages
+language-en = English
+language-cs = Čeština
+
+## Common
+common-return = <- Back
+common-return-main-menu = <- Main menu
+
+## Intro
+intro-skip = Press any key to skip, hold to skip the intro
+
+## Main menu
+main-menu-continue = CONTINUE
+main-menu-new-game = NEW GAME
+main-menu-load-game = LOAD GAME
+main-menu-settings = SETTINGS
+main-menu-additional-content = ADDITIONAL CONTENT
+main-menu-credits = CREDITS
+main-menu-quit-game = QUIT GAME
+main-menu-load-game-tooltip = There are no saves to load yet.
+
+## Character creator
+creator-title = Character creator
+creator-confirm = Confirm
+creator-gender = Gender
+creator-gender-female = Female
+creator-gender-male = Male
+creator-body = Body
+creator-body-1 = Body 1
+creator-body-2 = Body 2
+creator-body-3 = Body 3
+creator-color = Color
+creator-color-red = Red
+creator-color-blue = Blue
+creator-hair = Hair
+creator-hair-short = Short
+creator-hair-bun = Bun
+creator-hair-long = Long
+creator-hair-ponytail = Ponytail
+creator-beard = Beard
+creator-beard-none = None
+
+## Settings
+settings-title = Settings
+settings-language = Language
+settings-display = Display
+settings-display-windowed = Windowed
+settings-display-fullscreen = Fullscreen
+settings-language-tooltip = Language of all texts in the game.
+settings-display-tooltip = Play in a window or in borderless fullscreen.
+settings-screen-effects = Screen effects
+settings-screen-effects-tooltip = Strength of the scanlines, distortion, film grain and glitches.
+settings-screen-effects-off = Off
+settings-screen-effects-subtle = Subtle
+settings-screen-effects-full = Full
+settings-reduced-motion = Reduced motion
+settings-reduced-motion-tooltip = Turns off camera shake, head bob and sway.
+settings-off = Off
+settings-on = On
+settings-audio = Audio
+settings-volume-master = Master
+settings-volume-music = Music
+settings-volume-sfx = Effects
+settings-volume-ui = Interface
+settings-volume-ambience = Ambience
+settings-volume-voice = Voice
+settings-volume-tooltip = Volume of the sound category, the master volume scales all of them.
+settings-mute = Mute
+settings-mute-tooltip = Silences the sound category without changing its volume.
+settings-description-placeholder = Hover over a setting to see what it does.
+
+## Additional content
+content-title = Additional content
+content-empty = No content packs installed. Put them into the content folder next to the game.
+content-restart = Changes apply after restarting the game.
+content-pack-enabled = Enabled
+content-pack-disabled = Disabled
+content-pack-invalid = Invalid
+content-error-archive = Archives are not supported yet, extract the pack into a folder.
+content-error-no-manifest = The pack has no pack.toml manifest.
+content-error-missing-field = The manifest is missing a name, version or author.
+content-error-version = The version has to look like 1.0.0.
+content-error-thumbnail = The thumbnail file does not exist.
+
+## Loading
+loading-title = LOADING
+loading-tip-1 = Tip: Hold the intro skip key to skip the whole intro.
+loading-tip-2 = Tip: Content packs can replace any asset of the game.
+loading-tip-3 = Tip: Every menu can be navigated with a gamepad.
+loading-tip-4 = Tip: Hover over a setting to see what it does.
+loading-error-title = Some assets failed to load
+loading-continue = Continue anyway
+
+## Console
+console-placeholder = Type a command, help lists all commands
+
+## Tuning
+tuning-title = Tuning
+tuning-save = Save
+tuning-prone-speed = Prone speed
+tuning-crouch-speed = Crouch speed
+tuning-ads-speed = Aiming speed
+tuning-base-speed = Walking speed
+tuning-running-speed = Running speed
+tuning-sprinting-speed = Sprinting speed
+tuning-strafing-multiplier = Strafing multiplier
+tuning-backwards-multiplier = Backwards multiplier
+tuning-player-acceleration = Player acceleration
+tuning-controller-acceleration = Controller acceleration
+tuning-controller-damping = Controller damping
+tuning-controller-jump-impulse = Jump impulse
+tuning-controller-acceleration-multiplier = Controller input multiplier
+tuning-flicker-frequency = Flicker frequency
+tuning-flicker-intensity-min = Flicker min intensity
+tuning-flicker-intensity-max = Flicker max intensity
+tuning-flicker-threshold-min = Flicker min threshold
+tuning-flicker-threshold-max = Flicker max threshold
+tuning-flicker-smoothing = Flicker smoothing
+tuning-flicker-burst-chance = Flicker burst chance
+tuning-flicker-burst-count = Flicker burst length
+tuning-flicker-burst-interval = Flicker burst interval
+tuning-effect-scanlines = Scanlines
+tuning-effect-distortion = Barrel distortion
+tuning-effect-aberration = Chromatic aberration
+tuning-effect-vignette = Vignette
+tuning-effect-grain = Film grain
+tuning-effect-glitch = Glitch
+
+## Dialogs
+dialog-confirm = Confirm
+dialog-cancel = Cancel
+dialog-quit-title = Quit game
+dialog-quit-message = Do you really want to quit the game?
+dialog-discard-title = Discard changes
+dialog-discard-message = Your character changes will be lost.
+dialog-overwrite-title = Overwrite save
+dialog-overwrite-message = The selected save slot will be overwritten.
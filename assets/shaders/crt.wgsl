@@ -0,0 +1,61 @@
+// Post-process giving the cameras a worn CRT monitor look.
+// Every effect is scaled by its parameter, so a zero turns it off.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct CrtUniform {
+    scanline_intensity: f32,
+    scanline_count: f32,
+    distortion: f32,
+    aberration: f32,
+    vignette_intensity: f32,
+    vignette_radius: f32,
+    grain_intensity: f32,
+    glitch: f32,
+    time: f32,
+    // WebGL2 requires uniforms to be aligned to 16 bytes
+    _padding_a: f32,
+    _padding_b: f32,
+    _padding_c: f32,
+}
+@group(0) @binding(2) var<uniform> settings: CrtUniform;
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Barrel distortion, scaled so the corners stay in the corners
+    let centered = in.uv * 2.0 - 1.0;
+    let distorted = centered * (1.0 + settings.distortion * dot(centered, centered)) / (1.0 + settings.distortion * 2.0);
+    var uv = distorted * 0.5 + 0.5;
+
+    // Glitch shifts horizontal bands of the screen
+    let band = floor(uv.y * 24.0);
+    uv.x += (hash(vec2<f32>(band, floor(settings.time * 20.0))) - 0.5) * 0.06 * settings.glitch;
+
+    // Chromatic aberration, growing towards the edges and during the glitch
+    let split = (uv - 0.5) * (settings.aberration + 0.02 * settings.glitch);
+    let red = textureSample(screen_texture, texture_sampler, uv + split).r;
+    let green = textureSample(screen_texture, texture_sampler, uv).g;
+    let blue_alpha = textureSample(screen_texture, texture_sampler, uv - split).ba;
+    var color = vec3<f32>(red, green, blue_alpha.x);
+
+    // Scanlines
+    let scanline = sin(uv.y * settings.scanline_count * 3.14159265) * 0.5 + 0.5;
+    color *= 1.0 - settings.scanline_intensity * (1.0 - scanline);
+
+    // Vignette
+    let distance = length(centered) * 0.70710678;
+    let vignette = 1.0 - smoothstep(settings.vignette_radius - 0.5, settings.vignette_radius, distance);
+    color *= mix(1.0, vignette, settings.vignette_intensity);
+
+    // Film grain
+    color += (hash(in.uv * 1000.0 + fract(settings.time) * 100.0) - 0.5) * settings.grain_intensity;
+
+    return vec4<f32>(color, blue_alpha.y);
+}
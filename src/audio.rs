@@ -0,0 +1,282 @@
+use bevy::ecs::system::SystemParam;
+use bevy_kira_audio::PlayAudioCommand;
+#[cfg(not(target_family = "wasm"))]
+use bevy::asset::io::file::FileAssetReader;
+use crate::*;
+
+
+// #===================#
+// #=== AUDIO BUSES ===#
+
+/// File next to the game storing the volume and mute of every bus
+const VOLUME_SETTINGS: &str = "audio.toml";
+/// Seconds the settings have to stay unchanged before they are saved, so dragging a slider doesn't write every frame
+const VOLUME_SAVE_DELAY: f32 = 0.5;
+
+/// Category of sounds. Every bus plays on its own kira channel and has its own volume.
+/// The master bus is not a channel, it scales the volume of all buses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+    Music,
+    Sfx,
+    /// Plays on the main kira track, where Lunex plays its own sounds
+    Ui,
+    Ambience,
+    Voice,
+}
+impl AudioBus {
+    pub const ALL: [AudioBus; 5] = [AudioBus::Music, AudioBus::Sfx, AudioBus::Ui, AudioBus::Ambience, AudioBus::Voice];
+
+    /// Name used in the settings file, localization keys and console
+    pub fn name(&self) -> &'static str {
+        match self {
+            AudioBus::Music => "music",
+            AudioBus::Sfx => "sfx",
+            AudioBus::Ui => "ui",
+            AudioBus::Ambience => "ambience",
+            AudioBus::Voice => "voice",
+        }
+    }
+    /// Returns the bus with the name
+    pub fn from_name(name: &str) -> Option<Self> {
+        AudioBus::ALL.into_iter().find(|bus| bus.name() == name)
+    }
+}
+
+/// Kira channel of the music bus
+#[derive(Resource)]
+pub struct MusicChannel;
+/// Kira channel of the sfx bus
+#[derive(Resource)]
+pub struct SfxChannel;
+/// Kira channel of the ambience bus
+#[derive(Resource)]
+pub struct AmbienceChannel;
+/// Kira channel of the voice bus
+#[derive(Resource)]
+pub struct VoiceChannel;
+
+/// Volume and mute of a single bus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BusVolume {
+    /// Volume from 0 to 1
+    pub volume: f32,
+    pub muted: bool,
+}
+impl Default for BusVolume {
+    fn default() -> Self {
+        BusVolume { volume: 1.0, muted: false }
+    }
+}
+
+/// Volume settings of all buses, changes are applied to the channels and saved
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct VolumeSettings {
+    pub master: BusVolume,
+    pub buses: [BusVolume; AudioBus::ALL.len()],
+}
+impl VolumeSettings {
+    /// Returns the settings of the bus, none is the master bus
+    pub fn bus(&self, bus: Option<AudioBus>) -> BusVolume {
+        match bus {
+            Some(bus) => self.buses[bus as usize],
+            None => self.master,
+        }
+    }
+    /// Returns the mutable settings of the bus, none is the master bus
+    pub fn bus_mut(&mut self, bus: Option<AudioBus>) -> &mut BusVolume {
+        match bus {
+            Some(bus) => &mut self.buses[bus as usize],
+            None => &mut self.master,
+        }
+    }
+    /// Returns the final volume of the bus with the master and mutes applied
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        let (master, bus) = (self.master, self.bus(Some(bus)));
+        if master.muted || bus.muted { 0.0 } else { master.volume * bus.volume }
+    }
+    /// Parses the `name = volume` and `name_muted = true` pairs, unknown keys are ignored
+    pub fn parse(source: &str) -> Self {
+        let mut settings = VolumeSettings::default();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let (key, value) = (key.trim(), value.trim());
+            let (name, muted) = match key.strip_suffix("_muted") {
+                Some(name) => (name, true),
+                None => (key, false),
+            };
+            let bus = match name {
+                "master" => None,
+                name => match AudioBus::from_name(name) {
+                    Some(bus) => Some(bus),
+                    None => continue,
+                },
+            };
+            let target = settings.bus_mut(bus);
+            if muted {
+                if let Ok(value) = value.parse() { target.muted = value; }
+            } else if let Ok(value) = value.parse::<f32>() {
+                target.volume = value.clamp(0.0, 1.0);
+            }
+        }
+        settings
+    }
+    /// Writes the settings in the format read by [`VolumeSettings::parse`]
+    pub fn serialize(&self) -> String {
+        std::iter::once(("master", self.master))
+            .chain(AudioBus::ALL.into_iter().map(|bus| (bus.name(), self.bus(Some(bus)))))
+            .map(|(name, bus)| format!("{name} = {}\n{name}_muted = {}\n", bus.volume, bus.muted))
+            .collect()
+    }
+    /// Loads the settings saved next to the game
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load() -> Self {
+        std::fs::read_to_string(FileAssetReader::get_base_path().join(VOLUME_SETTINGS)).map(|source| VolumeSettings::parse(&source)).unwrap_or_default()
+    }
+    /// Settings are not persisted on the web
+    #[cfg(target_family = "wasm")]
+    pub fn load() -> Self {
+        VolumeSettings::default()
+    }
+    /// Saves the settings next to the game
+    fn save(&self) {
+        #[cfg(not(target_family = "wasm"))]
+        if let Err(error) = std::fs::write(FileAssetReader::get_base_path().join(VOLUME_SETTINGS), self.serialize()) {
+            warn!("Failed to save audio settings: {error}");
+        }
+    }
+}
+
+/// System parameter for playing sounds. Every playback in the game should go through it, so the sound respects its bus volume.
+///
+/// ```ignore
+/// fn system(audio: AudioBuses, assets: Res<AssetServer>) {
+///     audio.play(AudioBus::Music, assets.load(PreLoader::MUSIC)).looped();
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct AudioBuses<'w> {
+    settings: Res<'w, VolumeSettings>,
+    music: Res<'w, AudioChannel<MusicChannel>>,
+    sfx: Res<'w, AudioChannel<SfxChannel>>,
+    ui: Res<'w, Audio>,
+    ambience: Res<'w, AudioChannel<AmbienceChannel>>,
+    voice: Res<'w, AudioChannel<VoiceChannel>>,
+    args: Res<'w, LaunchArgs>,
+}
+impl AudioBuses<'_> {
+    /// Returns the final volume of the bus, everything stays silent when started with `--no-audio`
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        if self.args.no_audio { 0.0 } else { self.settings.volume(bus) }
+    }
+    /// Plays the sound on the bus
+    pub fn play(&self, bus: AudioBus, source: Handle<AudioSource>) -> PlayAudioCommand<'_> {
+        match bus {
+            AudioBus::Music => self.music.play(source),
+            AudioBus::Sfx => self.sfx.play(source),
+            AudioBus::Ui => self.ui.play(source),
+            AudioBus::Ambience => self.ambience.play(source),
+            AudioBus::Voice => self.voice.play(source),
+        }
+    }
+    /// Plays the sound on the bus, the volume is relative to the bus volume that the channel applies
+    pub fn play_with_volume(&self, bus: AudioBus, source: Handle<AudioSource>, volume: f64) -> PlayAudioCommand<'_> {
+        let mut command = self.play(bus, source);
+        command.with_volume(volume);
+        command
+    }
+    /// Stops all sounds of the bus
+    pub fn stop(&self, bus: AudioBus) {
+        match bus {
+            AudioBus::Music => { self.music.stop(); },
+            AudioBus::Sfx => { self.sfx.stop(); },
+            AudioBus::Ui => { self.ui.stop(); },
+            AudioBus::Ambience => { self.ambience.stop(); },
+            AudioBus::Voice => { self.voice.stop(); },
+        }
+    }
+    /// Stops all sounds of every bus
+    pub fn stop_all(&self) {
+        for bus in AudioBus::ALL { self.stop(bus); }
+    }
+    /// Sets the volume of the bus channel
+    fn set_volume(&self, bus: AudioBus, volume: f64) {
+        match bus {
+            AudioBus::Music => { self.music.set_volume(volume); },
+            AudioBus::Sfx => { self.sfx.set_volume(volume); },
+            AudioBus::Ui => { self.ui.set_volume(volume); },
+            AudioBus::Ambience => { self.ambience.set_volume(volume); },
+            AudioBus::Voice => { self.voice.set_volume(volume); },
+        }
+    }
+}
+
+/// System that applies the settings to the channels
+fn volume_settings_apply_system(audio: AudioBuses) {
+    for bus in AudioBus::ALL {
+        audio.set_volume(bus, audio.volume(bus) as f64);
+    }
+}
+
+fn command_volume(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let (name, value) = match arguments {
+        [name] => (*name, None),
+        [name, value] => (*name, Some(*value)),
+        _ => return Err("Expected a bus and an optional volume".into()),
+    };
+    let bus = match name {
+        "master" => None,
+        name => Some(AudioBus::from_name(name).ok_or_else(|| format!("Unknown bus \"{name}\""))?),
+    };
+    if let Some(value) = value {
+        let volume = match value {
+            "mute" | "unmute" => None,
+            value => Some(value.parse::<f32>().map_err(|_| format!("Invalid volume \"{value}\", expected a number from 0 to 1, mute or unmute"))?),
+        };
+        let mut settings = world.resource_mut::<VolumeSettings>();
+        let target = settings.bus_mut(bus);
+        match volume {
+            Some(volume) => target.volume = volume.clamp(0.0, 1.0),
+            None => target.muted = value == "mute",
+        }
+    }
+    let target = world.resource::<VolumeSettings>().bus(bus);
+    Ok(format!("Volume of {name} is {}{}", target.volume, if target.muted { " (muted)" } else { "" }))
+}
+
+fn command_volume_complete(_world: &World) -> Vec<String> {
+    std::iter::once("master").chain(AudioBus::ALL.iter().map(|bus| bus.name())).map(String::from).collect()
+}
+
+/// System that saves the settings once they stop changing or when the game exits
+fn volume_settings_save_system(time: Res<Time>, settings: Res<VolumeSettings>, mut pending: Local<Option<f32>>, exit: EventReader<bevy::app::AppExit>) {
+    if settings.is_changed() && !settings.is_added() { *pending = Some(VOLUME_SAVE_DELAY); }
+    let Some(remaining) = pending.as_mut() else { return };
+    *remaining -= time.delta_seconds();
+    if *remaining <= 0.0 || !exit.is_empty() {
+        settings.save();
+        *pending = None;
+    }
+}
+
+
+// #====================#
+// #=== AUDIO PLUGIN ===#
+
+/// Plugin adding the audio buses
+pub struct AudioBusPlugin;
+impl Plugin for AudioBusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_audio_channel::<MusicChannel>()
+            .add_audio_channel::<SfxChannel>()
+            .add_audio_channel::<AmbienceChannel>()
+            .add_audio_channel::<VoiceChannel>()
+            .insert_resource(VolumeSettings::load())
+            .add_console_command("volume", ConsoleCommand::new("<bus> [volume|mute|unmute]", "Prints or sets the volume of the audio bus", command_volume).complete(command_volume_complete))
+            .add_systems(Update, volume_settings_apply_system.run_if(resource_changed::<VolumeSettings>))
+            .add_systems(Update, volume_settings_save_system);
+    }
+}
@@ -25,12 +25,8 @@ impl BevypunkColorPalette for Color {
 // #======================================#
 // #=== ASSET CACHE FOR SMOOTH LOADING ===#
 
-// Load the gif before the app is run
-#[derive(Resource)]
-pub struct PreLoader {
-    #[cfg(not(target_family = "wasm"))]
-    pub intro: Handle<AnimatedImage>,
-}
+/// Paths to all assets of the game
+pub struct PreLoader;
 
 #[allow(dead_code)]
 impl PreLoader {
@@ -38,6 +34,23 @@ impl PreLoader {
     pub const MUSIC: &'static str = "sounds/main_menu.ogg";
     pub const MUSIC_INTRO: &'static str = "sounds/intro.ogg";
     pub const SFX_UI: &'static str = "sounds/ui_ping.ogg";
+    pub const SFX_UI_FOCUS: &'static str = "sounds/ui_focus.wav";
+    pub const SFX_UI_CLICK: &'static str = "sounds/ui_click.wav";
+    pub const SFX_UI_CHANGE: &'static str = "sounds/ui_change.wav";
+    pub const SFX_UI_DENIED: &'static str = "sounds/ui_denied.wav";
+    pub const SFX_UI_BACK: &'static str = "sounds/ui_back.wav";
+    pub const STINGER_CONFIRM: &'static str = "sounds/ui_ping.ogg";
+
+    // World sounds
+    pub const SFX_NEON_BUZZ: &'static str = "sounds/neon_buzz.wav";
+    pub const SFX_TERMINAL: &'static str = "sounds/terminal_hum.wav";
+    pub const SFX_CITY: &'static str = "sounds/city_ambience.wav";
+    pub const FOOTSTEPS_CONCRETE: [&'static str; 2] = ["sounds/footstep_concrete_1.wav", "sounds/footstep_concrete_2.wav"];
+    pub const FOOTSTEPS_METAL: [&'static str; 2] = ["sounds/footstep_metal_1.wav", "sounds/footstep_metal_2.wav"];
+    pub const FOOTSTEPS_WATER: [&'static str; 2] = ["sounds/footstep_water_1.wav", "sounds/footstep_water_2.wav"];
+    pub const LANDING_CONCRETE: &'static str = "sounds/landing_concrete.wav";
+    pub const LANDING_METAL: &'static str = "sounds/landing_metal.wav";
+    pub const LANDING_WATER: &'static str = "sounds/landing_water.wav";
 
     // Fonts
     pub const FONT_LIGHT: &'static str = "fonts/rajdhani/Rajdhani-Light.ttf";
@@ -63,6 +76,7 @@ impl PreLoader {
 
     // Routes
     pub const INTRO_BACKGROUND: &'static str = "images/intro/frame_blank.png";
+    pub const INTRO_SEQUENCE: &'static str = "intro.slides";
 
     pub const MAIN_BACKGROUND: &'static str = "images/settings/background.png";
     pub const MAIN_BOARD: &'static str = "images/main_menu/board.png";
@@ -71,21 +85,63 @@ impl PreLoader {
     pub const SETTINGS_BACKGROUND: &'static str = "images/settings/background.png";
 
     pub const CHARACTER_CREATOR_PANEL: &'static str = "images/character_creator/panel.png";
+
+    pub const CREDITS: &'static str = "credits.md";
+
+    pub const TUNING: &'static str = "tuning.tuning";
+
+    pub const SHADER_CRT: &'static str = "shaders/crt.wgsl";
+
+    pub const HUD: &'static str = "images/hud/hud.png";
+
+    // Particles
+    pub const PARTICLES_DUST: &'static str = "particles/dust.particles";
+    pub const PARTICLES_NEON_SPARKS: &'static str = "particles/neon_sparks.particles";
+    pub const PARTICLES_RAIN: &'static str = "particles/rain.particles";
+    pub const PARTICLES_SPARKS: &'static str = "particles/sparks.particles";
+    pub const PARTICLES_STEAM: &'static str = "particles/steam.particles";
+
+    // Scenes
+    pub const SKYBOX: &'static str = "scenes/skybox/skybox.ktx2";
+    pub const SKYBOX_DIFFUSE: &'static str = "scenes/skybox/diffuse_map.ktx2";
+    pub const SKYBOX_SPECULAR: &'static str = "scenes/skybox/specular_map.ktx2";
+
+    // Models
+    pub const CHARACTER_GENDERS: [&'static str; 2] = ["Female", "Male"];
+    pub const CHARACTER_BODIES: [u8; 3] = [1, 2, 3];
+
+    /// Path to the character model, without the scene label
+    pub fn character_model(gender: &str, body: u8) -> String {
+        format!("models/{gender}{body}.glb")
+    }
+
+    /// Assets shared by every route, they are part of every route manifest
+    pub const MANIFEST_COMMON: [&'static str; 22] = [
+        PreLoader::FONT_LIGHT, PreLoader::FONT_REGULAR, PreLoader::FONT_MEDIUM, PreLoader::FONT_SEMIBOLD, PreLoader::FONT_BOLD,
+        PreLoader::CURSOR,
+        PreLoader::BUTTON_SYMETRIC, PreLoader::BUTTON_SYMETRIC_SLICED,
+        PreLoader::BUTTON_SLICED_BOTTOM_LEFT, PreLoader::BUTTON_SLICED_BOTTOM_RIGHT, PreLoader::BUTTON_SLICED_TOP_LEFT, PreLoader::BUTTON_SLICED_TOP_RIGHT,
+        PreLoader::CHEVRON_LEFT, PreLoader::CHEVRON_RIGHT,
+        PreLoader::SWITCH_BASE, PreLoader::SWITCH_HEAD,
+        PreLoader::SFX_UI, PreLoader::SFX_UI_FOCUS, PreLoader::SFX_UI_CLICK, PreLoader::SFX_UI_CHANGE, PreLoader::SFX_UI_DENIED, PreLoader::SFX_UI_BACK,
+    ];
 }
 
 
 // #======================================#
 // #=== JUST SPAWN PRESETS FOR CLARITY ===#
 
-/// Function to return default plugins with correct settings
-pub fn default_plugins() -> PluginGroupBuilder {
+/// Function to return default plugins with correct settings, the window can be overridden from the command line
+pub fn default_plugins(args: &LaunchArgs) -> PluginGroupBuilder {
+    let mode = args.window_mode.unwrap_or(bevy::window::WindowMode::Windowed);
+    let resolution = args.resolution.unwrap_or(if mode == bevy::window::WindowMode::Windowed { Vec2::new(1280.0, 720.0) } else { Vec2::new(1920.0, 1080.0) });
     DefaultPlugins.set (
         WindowPlugin {
             primary_window: Some(Window {
                 title: "Bevypunk".into(),
-                mode: bevy::window::WindowMode::Windowed,
+                mode,
                 present_mode: bevy::window::PresentMode::AutoNoVsync,
-                resolution: bevy::window::WindowResolution::new(1280.0, 720.0),
+                resolution: bevy::window::WindowResolution::new(resolution.x, resolution.y),
                 ..default()
             }),
             ..default()
@@ -105,7 +161,6 @@ pub fn default_plugins() -> PluginGroupBuilder {
         ..default()
     })
     .add_group(UiGenericPlugins::<Ui3d>::new())
-    //.add(UiDebugPlugin::<Ui3d>::new())
     .add(AudioPlugin)
 }
 
@@ -122,18 +177,15 @@ pub fn camera() -> impl Bundle {
             //tonemapping: Tonemapping::None,
             ..default()
         },
-        VFXBloomFlicker,
+        VFXBloomFlicker::default(),
         BloomSettings::OLD_SCHOOL,
         InheritedVisibility::default(),
-        /*VfxWiggleCamera {
-            sinusoid: vec![
-                Sine {
-                    speed: 0.005,
-                    amplitude: 0.003,
-                    degree: 0.0,
-                }
-            ]
-        }*/
+
+        // Screen effects, the distortion and glitch are only on the game camera because they move the UI away from its hit zones
+        Scanlines::default(),
+        ChromaticAberration::default(),
+        Vignette::default(),
+        FilmGrain::default(),
     )
 }
 
@@ -141,50 +193,114 @@ pub fn camera() -> impl Bundle {
 // #===============================#
 // #=== VFX LOGIC AND ANIMATION ===#
 
-use std::f32::consts::TAU;
-use rand::Rng;
-
-#[derive(Component)]
-pub struct VFXBloomFlicker;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
-/// System for immitating flickering by randomly adjusting cameras bloom values
-fn vfx_bloom_flicker(mut query: Query<&mut BloomSettings, With<VFXBloomFlicker>>) {
-    for mut bloom in &mut query {
-        let mut rng = rand::thread_rng();
-        if rng.gen_range(0..100) < 20 {
-            bloom.intensity += (rng.gen_range(0.20..0.30)-bloom.intensity)/6.0;
-            bloom.prefilter_settings.threshold += (rng.gen_range(0.20..0.30)-bloom.prefilter_settings.threshold)/4.0;
-        }
+/// Random generator used by the VFX, seeded with `--seed` to make them reproducible
+#[derive(Resource, Debug, Clone)]
+pub struct GameRng(pub StdRng);
+impl GameRng {
+    pub fn new(seed: Option<u64>) -> Self {
+        GameRng(match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        })
     }
 }
 
-#[derive(Clone, Default)]
-pub struct Sine {
-    pub speed: f32,
-    pub amplitude: f32,
-    pub degree: f32,
+/// Add this component to a camera to make its bloom flicker with the active [`BloomFlickerProfile`]
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct VFXBloomFlicker {
+    /// Seconds until the next flicker
+    next: f32,
+    /// Flickers left in the current burst
+    burst: u32,
+    /// Bloom values the camera moves towards
+    intensity: f32,
+    threshold: f32,
 }
-impl Sine {
-    fn tick(&mut self) {
-        self.degree += self.speed; 
-        if self.degree >= TAU { self.degree -= TAU; }
-        if self.degree < 0.0 { self.degree += TAU; }
-    }
-    fn get_pure(&self) -> f32 {
-        self.degree.sin()*self.amplitude
+
+/// How the bloom flickers. The resource is the default profile and it is editable from the tuning panel.
+/// A route can pick a different profile by adding it as a component to its route entity.
+#[derive(Resource, Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct BloomFlickerProfile {
+    /// Average number of flickers per second
+    pub frequency: f32,
+    pub intensity_min: f32,
+    pub intensity_max: f32,
+    pub threshold_min: f32,
+    pub threshold_max: f32,
+    /// How fast the bloom follows a flicker, higher is snappier
+    pub smoothing: f32,
+    /// Chance that a flicker starts a burst of quick flickers
+    pub burst_chance: f32,
+    /// Number of extra flickers in a burst
+    pub burst_count: u32,
+    /// Seconds between the flickers of a burst
+    pub burst_interval: f32,
+}
+impl BloomFlickerProfile {
+    /// Restless flicker of the menus
+    pub const MENU: BloomFlickerProfile = BloomFlickerProfile {
+        frequency: 12.0,
+        intensity_min: 0.20,
+        intensity_max: 0.30,
+        threshold_min: 0.20,
+        threshold_max: 0.30,
+        smoothing: 3.0,
+        burst_chance: 0.05,
+        burst_count: 3,
+        burst_interval: 0.04,
+    };
+    /// Barely noticeable flicker that does not distract from gameplay
+    pub const CALM: BloomFlickerProfile = BloomFlickerProfile {
+        frequency: 0.5,
+        intensity_min: 0.22,
+        intensity_max: 0.25,
+        threshold_min: 0.24,
+        threshold_max: 0.27,
+        smoothing: 1.0,
+        burst_chance: 0.0,
+        burst_count: 0,
+        burst_interval: 0.0,
+    };
+}
+impl Default for BloomFlickerProfile {
+    fn default() -> Self {
+        BloomFlickerProfile::MENU
     }
 }
 
-#[derive(Component, Clone, Default)]
-pub(super) struct VfxWiggleCamera {
-    pub sinusoid: Vec<Sine>
-}
-fn vfx_camera_wiggle(mut query: Query<(&mut VfxWiggleCamera, &mut Transform)>) {
-    for (mut animation, mut transform) in &mut query {
-        for sine in &mut animation.sinusoid {
-            sine.tick()
+/// System for immitating flickering by adjusting cameras bloom values.
+/// Flickers are a Poisson process in time, so they look the same at any frame rate and are reproducible with `--seed`.
+fn vfx_bloom_flicker(time: Res<Time>, mut query: Query<(&mut BloomSettings, &mut VFXBloomFlicker)>, mut rng: ResMut<GameRng>, default_profile: Res<BloomFlickerProfile>, routes: Query<&BloomFlickerProfile>) {
+    let profile = routes.iter().last().unwrap_or(&default_profile);
+    let delta = time.delta_seconds();
+    for (mut bloom, mut flicker) in &mut query {
+        let rng = &mut rng.0;
+
+        // Roll all flickers that happened since the last frame
+        flicker.next = if profile.frequency > 0.0 { flicker.next - delta } else { 0.0 };
+        while flicker.next <= 0.0 && profile.frequency > 0.0 {
+            flicker.intensity = profile.intensity_min.lerp(profile.intensity_max, rng.gen());
+            flicker.threshold = profile.threshold_min.lerp(profile.threshold_max, rng.gen());
+
+            if flicker.burst == 0 && profile.burst_count > 0 && rng.gen::<f32>() < profile.burst_chance {
+                flicker.burst = profile.burst_count;
+            }
+            let wait = if flicker.burst > 0 {
+                flicker.burst -= 1;
+                profile.burst_interval
+            } else {
+                -(1.0 - rng.gen::<f32>()).ln() / profile.frequency
+            };
+            flicker.next += wait.max(0.001);
         }
-        transform.rotation.z = animation.sinusoid[0].get_pure();
+
+        // Move towards the flicker independently of the frame rate
+        let factor = 1.0 - (-profile.smoothing * delta).exp();
+        bloom.intensity += (flicker.intensity - bloom.intensity) * factor;
+        bloom.prefilter_settings.threshold += (flicker.threshold - bloom.prefilter_settings.threshold) * factor;
     }
 }
 
@@ -192,6 +308,18 @@ fn vfx_camera_wiggle(mut query: Query<(&mut VfxWiggleCamera, &mut Transform)>) {
 pub struct VFXPlugin;
 impl Plugin for VFXPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (vfx_bloom_flicker, vfx_camera_wiggle));
+        app
+            .register_tunables::<BloomFlickerProfile>(vec![
+                Tunable::new("frequency", "tuning-flicker-frequency", 0.0, 30.0).step(0.5),
+                Tunable::new("intensity_min", "tuning-flicker-intensity-min", 0.0, 1.0).step(0.01),
+                Tunable::new("intensity_max", "tuning-flicker-intensity-max", 0.0, 1.0).step(0.01),
+                Tunable::new("threshold_min", "tuning-flicker-threshold-min", 0.0, 1.0).step(0.01),
+                Tunable::new("threshold_max", "tuning-flicker-threshold-max", 0.0, 1.0).step(0.01),
+                Tunable::new("smoothing", "tuning-flicker-smoothing", 0.1, 20.0).step(0.1),
+                Tunable::new("burst_chance", "tuning-flicker-burst-chance", 0.0, 1.0).step(0.01),
+                Tunable::new("burst_count", "tuning-flicker-burst-count", 0.0, 10.0).step(1.0),
+                Tunable::new("burst_interval", "tuning-flicker-burst-interval", 0.0, 0.5).step(0.01),
+            ])
+            .add_systems(Update, vfx_bloom_flicker);
     }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+use std::f32::consts::TAU;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Channel of the camera transform a motion layer writes into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionAxis {
+    X,
+    Y,
+    Z,
+    Pitch,
+    Yaw,
+    Roll,
+}
+
+/// Add this component to a camera to move it with procedural motion layers ([`CameraShake`], [`CameraWiggle`], ...).
+/// The layers are added on top of the transform the camera had when this component was added,
+/// so the camera should be a child of the entity that is moved by the controller.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct CameraMotion {
+    /// Transform the layers are added to
+    base: Option<Transform>,
+    /// Offset along the local axes, summed from all layers this frame
+    translation: Vec3,
+    /// Pitch, yaw and roll in radians, summed from all layers this frame
+    rotation: Vec3,
+}
+impl CameraMotion {
+    /// Adds the value to the channel, call this from the layer systems in [`CameraMotionSystems::Layers`]
+    pub fn add(&mut self, axis: MotionAxis, value: f32) {
+        match axis {
+            MotionAxis::X => self.translation.x += value,
+            MotionAxis::Y => self.translation.y += value,
+            MotionAxis::Z => self.translation.z += value,
+            MotionAxis::Pitch => self.rotation.x += value,
+            MotionAxis::Yaw => self.rotation.y += value,
+            MotionAxis::Roll => self.rotation.z += value,
+        }
+    }
+}
+
+/// Trauma based screen shake layer. Trauma decays over time and the shake strength is trauma squared,
+/// so small hits are subtle and big hits stack up into a violent shake.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct CameraShake {
+    /// Current trauma from 0 to 1
+    pub trauma: f32,
+    /// How much trauma is lost per second
+    pub decay: f32,
+    /// How fast the camera shakes
+    pub frequency: f32,
+    /// Offset at full trauma
+    pub max_translation: Vec3,
+    /// Pitch, yaw and roll in radians at full trauma
+    pub max_rotation: Vec3,
+    time: f32,
+}
+impl Default for CameraShake {
+    fn default() -> Self {
+        CameraShake {
+            trauma: 0.0,
+            decay: 1.0,
+            frequency: 15.0,
+            max_translation: Vec3::new(0.05, 0.05, 0.0),
+            max_rotation: Vec3::new(0.05, 0.05, 0.08),
+            time: 0.0,
+        }
+    }
+}
+impl CameraShake {
+    /// Adds trauma, the result is clamped to 1
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+}
+
+/// Event adding trauma to every [`CameraShake`]
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct AddTrauma(pub f32);
+
+/// Single periodic motion of the [`CameraWiggle`] layer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Wiggle {
+    /// Smooth back and forth motion
+    Sine { axis: MotionAxis, frequency: f32, amplitude: f32 },
+    /// Organic motion without a visible period, the seed makes different axes independent
+    Noise { axis: MotionAxis, frequency: f32, amplitude: f32, seed: u32 },
+}
+
+/// Layer summing multiple sine and noise wiggles on any axes, for example idle breathing sway
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct CameraWiggle {
+    pub wiggles: Vec<Wiggle>,
+    time: f32,
+}
+impl CameraWiggle {
+    pub fn new(wiggles: Vec<Wiggle>) -> Self {
+        CameraWiggle { wiggles, time: 0.0 }
+    }
+}
+
+/// Accessibility settings of the camera motion
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct MotionSettings {
+    /// Disables all camera motion layers
+    pub reduced_motion: bool,
+}
+
+/// Ordering of the camera motion systems
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CameraMotionSystems {
+    /// Layers add their offsets to the [`CameraMotion`]
+    Layers,
+    /// The summed offsets are written into the transform
+    Apply,
+}
+
+
+// #=====================#
+// #=== MOTION LAYERS ===#
+
+/// Smooth 1D value noise in range from -1 to 1
+pub fn motion_noise(time: f32, seed: u32) -> f32 {
+    let hash = |index: i32| {
+        let mut x = (index as u32).wrapping_mul(0x27d4eb2d) ^ seed.wrapping_mul(0x165667b1);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x85ebca6b);
+        x ^= x >> 13;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+    let index = time.floor();
+    let t = time - index;
+    let t = t * t * (3.0 - 2.0 * t);
+    hash(index as i32).lerp(hash(index as i32 + 1), t)
+}
+
+/// System that decays the trauma and adds the shake
+fn camera_shake_system(time: Res<Time>, mut events: EventReader<AddTrauma>, mut query: Query<(&mut CameraShake, &mut CameraMotion)>) {
+    let trauma: f32 = events.read().map(|event| event.0).sum();
+    for (mut shake, mut motion) in &mut query {
+        if trauma != 0.0 { shake.add_trauma(trauma); }
+        if shake.trauma <= 0.0 { continue; }
+
+        shake.time += time.delta_seconds() * shake.frequency;
+        let strength = shake.trauma * shake.trauma;
+        let (translation, rotation) = (shake.max_translation, shake.max_rotation);
+        for (seed, axis, max) in [
+            (1, MotionAxis::X, translation.x), (2, MotionAxis::Y, translation.y), (3, MotionAxis::Z, translation.z),
+            (4, MotionAxis::Pitch, rotation.x), (5, MotionAxis::Yaw, rotation.y), (6, MotionAxis::Roll, rotation.z),
+        ] {
+            motion.add(axis, max * strength * motion_noise(shake.time, seed));
+        }
+
+        shake.trauma = (shake.trauma - shake.decay * time.delta_seconds()).max(0.0);
+    }
+}
+
+/// System that adds the wiggles
+fn camera_wiggle_system(time: Res<Time>, mut query: Query<(&mut CameraWiggle, &mut CameraMotion)>) {
+    for (mut wiggle, mut motion) in &mut query {
+        wiggle.time += time.delta_seconds();
+        for layer in &wiggle.wiggles {
+            match *layer {
+                Wiggle::Sine { axis, frequency, amplitude } => motion.add(axis, (wiggle.time * frequency * TAU).sin() * amplitude),
+                Wiggle::Noise { axis, frequency, amplitude, seed } => motion.add(axis, motion_noise(wiggle.time * frequency, seed) * amplitude),
+            }
+        }
+    }
+}
+
+/// System that writes the summed layers into the transform and resets them for the next frame
+fn camera_motion_apply_system(settings: Res<MotionSettings>, mut query: Query<(&mut CameraMotion, &mut Transform)>) {
+    for (mut motion, mut transform) in &mut query {
+        let base = *motion.base.get_or_insert(*transform);
+        let (translation, rotation) = if settings.reduced_motion { (Vec3::ZERO, Vec3::ZERO) } else { (motion.translation, motion.rotation) };
+
+        let target = Transform {
+            translation: base.translation + base.rotation * translation,
+            rotation: base.rotation * Quat::from_euler(EulerRot::YXZ, rotation.y, rotation.x, rotation.z),
+            scale: base.scale,
+        };
+        if *transform != target { *transform = target; }
+
+        motion.translation = Vec3::ZERO;
+        motion.rotation = Vec3::ZERO;
+    }
+}
+
+
+// #=====================#
+// #=== MOTION PLUGIN ===#
+
+/// Plugin adding the camera motion stack
+pub struct CameraMotionPlugin;
+impl Plugin for CameraMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<MotionSettings>()
+            .add_event::<AddTrauma>()
+            .configure_sets(Update, (CameraMotionSystems::Layers, CameraMotionSystems::Apply).chain())
+            .add_systems(Update, (camera_shake_system, camera_wiggle_system).in_set(CameraMotionSystems::Layers))
+            .add_systems(Update, camera_motion_apply_system.in_set(CameraMotionSystems::Apply));
+    }
+}
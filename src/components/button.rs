@@ -4,10 +4,35 @@ use crate::*;
 // #=========================#
 // #=== EXPOSED COMPONENT ===#
 
-/// When this component is added, a UI system is built
+/// When this component is added, a UI system is built.
+/// The `text` field is a localization key. Changing any field after spawn updates the button.
 #[derive(Component, Debug, Default, Clone, PartialEq)]
 pub struct Button {
     pub text: String,
+    /// Disabled button does not react to hover and does not emit click events
+    pub disabled: bool,
+    /// Selected button is highlighted as the active one in its group
+    pub selected: bool,
+    /// Set by the button itself while it is held down
+    pub pressed: bool,
+    /// Optional leading icon
+    pub icon: Option<ButtonIcon>,
+    /// Optional right-aligned hint, for example a keybinding glyph. Displayed as is.
+    pub hint: Option<String>,
+    /// Optional small line under the label. Displayed as is.
+    pub subtitle: Option<String>,
+}
+impl Button {
+    /// Returns the resolved state of the button
+    pub fn state(&self) -> WidgetState {
+        WidgetState::resolve(self.disabled, self.pressed, self.selected)
+    }
+}
+impl StatefulWidget for Button {
+    fn disabled(&self) -> bool { self.disabled }
+    fn selected(&self) -> bool { self.selected }
+    fn pressed(&self) -> bool { self.pressed }
+    fn set_pressed(&mut self, pressed: bool) { self.pressed = pressed; }
 }
 
 
@@ -18,14 +43,56 @@ pub struct Button {
 #[derive(Component, Debug, Default, Clone, PartialEq)]
 struct ButtonUi;
 
+/// Entities of the button parts that change with the button state
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ButtonParts {
+    image: Entity,
+    text: Entity,
+    icon: Entity,
+    hint: Entity,
+    subtitle: Entity,
+    zone: Entity,
+}
+
+/// Returns base and hover color of the button image for the given state
+fn image_colors(state: WidgetState) -> (Color, Color) {
+    match state {
+        WidgetState::Base => (Color::BEVYPUNK_RED.with_alpha(0.0), Color::BEVYPUNK_YELLOW),
+        WidgetState::Pressed => (Color::BEVYPUNK_YELLOW.with_alpha(1.5), Color::BEVYPUNK_YELLOW.with_alpha(1.5)),
+        WidgetState::Selected => (Color::BEVYPUNK_BLUE.with_alpha(0.15), Color::BEVYPUNK_YELLOW),
+        WidgetState::Disabled => (Color::BEVYPUNK_RED_DIM.with_alpha(0.0), Color::BEVYPUNK_RED_DIM.with_alpha(0.0)),
+    }
+}
+
+/// Returns base and hover color of the button text for the given state
+fn text_colors(state: WidgetState) -> (Color, Color) {
+    match state {
+        WidgetState::Base => (Color::BEVYPUNK_RED, Color::BEVYPUNK_YELLOW),
+        WidgetState::Pressed => (Color::BEVYPUNK_RED_DIM, Color::BEVYPUNK_RED_DIM),
+        WidgetState::Selected => (Color::BEVYPUNK_BLUE, Color::BEVYPUNK_YELLOW),
+        WidgetState::Disabled => (Color::BEVYPUNK_RED_DIM.with_alpha(0.5), Color::BEVYPUNK_RED_DIM.with_alpha(0.5)),
+    }
+}
+
+/// Returns the layout of the label, which moves up to make space for the subtitle
+fn text_layout(button: &Button) -> UiLayout<Base> {
+    let y = if button.subtitle.is_some() { 40.0 } else { 50.0 };
+    UiLayout::window().pos(Rl((50., y))).anchor(Anchor::Center).pack::<Base>()
+}
+
 /// System that builds the component UI
 fn build_component (mut commands: Commands, query: Query<(Entity, &Button), Added<Button>>, assets: Res<AssetServer>) {
     for (entity, button_source) in &query {
 
+        let (image_base, image_hover) = image_colors(button_source.state());
+        let (text_base, text_hover) = text_colors(button_source.state());
+        let mut parts = None;
+
         // This will create a private sandboxed UiTree within the entity just for the button
-        commands.entity(entity).insert(
+        commands.entity(entity).insert((
             UiTreeBundle::<ButtonUi>::from(UiTree::new2d("Button")),
-        ).with_children(|ui| {
+            WidgetStateBlend::target(button_source),
+        )).with_children(|ui| {
 
             // Spawn button image
             let image = ui.spawn((
@@ -47,11 +114,14 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Button), Adde
                 // This is required to control our hover animation
                 UiAnimator::<Hover>::new().receiver(true),
 
-                // This will set the base color to red
-                UiColor::<Base>::new(Color::BEVYPUNK_RED.with_alpha(0.0)),
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(image_base),
 
-                // This will set hover color to yellow
-                UiColor::<Hover>::new(Color::BEVYPUNK_YELLOW),
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(image_hover),
+
+                // This will blend the colors with the button state
+                WidgetStyle::new(entity, image_colors),
 
             )).id();
 
@@ -62,11 +132,11 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Button), Adde
 
                 // Here we can define where we want to position our text within the parent node,
                 // don't worry about size, that is picked up and overwritten automaticaly by Lunex to match text size.
-                UiLayout::window().pos(Rl((50., 50.))).anchor(Anchor::Center).pack::<Base>(),
+                text_layout(button_source),
 
                 // Add text
                 UiText2dBundle {
-                    text: Text::from_section(&button_source.text,
+                    text: Text::from_section("",
                         TextStyle {
                             font: assets.load(PreLoader::FONT_MEDIUM),
                             font_size: 60.0,    // Currently hardcoded as Relative height (Rh) - so 60% of the node height
@@ -75,21 +145,124 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Button), Adde
                     ..default()
                 },
 
+                // Text is looked up from the string table
+                LocalizedText::new(&button_source.text),
+
                 // Make it non-obsructable for hit checking (mouse detection)
                 Pickable::IGNORE,
 
                 // This is required to control our hover animation
                 UiAnimator::<Hover>::new().receiver(true),
 
-                // This will set the color to red
-                UiColor::<Base>::new(Color::BEVYPUNK_RED),
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
 
-                // This will set hover color to yellow
-                UiColor::<Hover>::new(Color::BEVYPUNK_YELLOW),
+                // This will blend the colors with the button state
+                WidgetStyle::new(entity, text_colors),
+            )).id();
+
+            // Spawn button icon
+            let icon = ui.spawn((
+                // Link this widget
+                UiLink::<ButtonUi>::path("Control/Image/Icon"),
+
+                // Add layout
+                UiLayout::window().pos((Rh(20.0), Rl(50.0))).anchor(Anchor::CenterLeft).size((Rh(55.0), Rh(55.0))).pack::<Base>(),
+
+                // Give it an image, it is hidden if the button has no icon
+                UiImage2dBundle::from(button_source.icon.as_ref().map(|icon| icon.image()).unwrap_or_default()),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
+
+                // This will blend the colors with the button state
+                WidgetStyle::new(entity, text_colors),
+            )).insert(if button_source.icon.is_some() { Visibility::Inherited } else { Visibility::Hidden }).id();
+
+            // Spawn button hint
+            let hint = ui.spawn((
+                // Link this widget
+                UiLink::<ButtonUi>::path("Control/Image/Hint"),
+
+                // Align the hint to the right edge
+                UiLayout::window().pos((Rl(100.0) - Rh(20.0), Rl(50.0))).anchor(Anchor::CenterRight).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section(button_source.hint.clone().unwrap_or_default(),
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_MEDIUM),
+                            font_size: 40.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
+
+                // This will blend the colors with the button state
+                WidgetStyle::new(entity, text_colors),
+            )).id();
+
+            // Spawn button subtitle
+            let subtitle = ui.spawn((
+                // Link this widget
+                UiLink::<ButtonUi>::path("Control/Image/Subtitle"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((50., 78.))).anchor(Anchor::Center).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section(button_source.subtitle.clone().unwrap_or_default(),
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_REGULAR),
+                            font_size: 28.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
+
+                // This will blend the colors with the button state
+                WidgetStyle::new(entity, text_colors),
             )).id();
 
             // Spawn button hover-zone
-            ui.spawn((
+            let zone = ui.spawn((
                 // Link this widget
                 UiLink::<ButtonUi>::path("Control"),
 
@@ -103,15 +276,70 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Button), Adde
                 UiAnimator::<Hover>::new().forward_speed(5.0).backward_speed(1.0),
 
                 // This will pipe this hover data to the specified entities
-                UiAnimatorPipe::<Hover>::new(vec![text, image]),
+                UiAnimatorPipe::<Hover>::new(vec![text, image, icon, hint, subtitle]),
 
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
+            )).id();
+
+            parts = Some(ButtonParts { image, text, icon, hint, subtitle, zone });
+        });
+
+        // Store the parts so we can update them later
+        if let Some(parts) = parts {
+            commands.entity(entity).insert(parts);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that will update the button parts when the component changes
+fn button_update_system(mut commands: Commands, query: Query<(Entity, &Button, &ButtonParts), Or<(Changed<Button>, Added<ButtonParts>)>>, mut localized: Query<&mut LocalizedText>, mut text: Query<&mut Text, Without<LocalizedText>>) {
+    for (entity, button, parts) in &query {
+
+        // Update the label
+        if let Ok(mut text) = localized.get_mut(parts.text) {
+            if text.key != button.text { text.key = button.text.clone(); }
+        }
+
+        // Update the hint and the subtitle
+        for (part, value) in [(parts.hint, &button.hint), (parts.subtitle, &button.subtitle)] {
+            if let Ok(mut text) = text.get_mut(part) {
+                let value = value.clone().unwrap_or_default();
+                if text.sections[0].value != value { text.sections[0].value = value; }
+            }
+        }
+
+        // Update the icon
+        match &button.icon {
+            Some(icon) => {
+                commands.entity(parts.icon).insert((icon.image(), Visibility::Inherited));
+                match icon.atlas() {
+                    Some(atlas) => commands.entity(parts.icon).insert(atlas),
+                    None => commands.entity(parts.icon).remove::<TextureAtlas>(),
+                };
+            },
+            None => { commands.entity(parts.icon).insert(Visibility::Hidden); },
+        }
+
+        // Update the layout, colors are blended by the widget state systems
+        commands.entity(parts.text).insert(text_layout(button));
+
+        // Disabled button does not emit clicks or change the cursor
+        if button.disabled {
+            commands.entity(parts.zone).remove::<(OnHoverSetCursor, UiClickEmitter)>();
+        } else {
+            commands.entity(parts.zone).insert((
                 // This will change cursor icon on mouse hover
                 OnHoverSetCursor::new(CursorIcon::Pointer),
 
                 // If we click on this hover zone, it will emmit UiClick event from parent entity
                 UiClickEmitter::new(entity),
             ));
-        });
+        }
     }
 }
 
@@ -127,7 +355,11 @@ impl Plugin for ButtonPlugin {
             // Add Lunex plugins for our sandboxed UI
             .add_plugins(UiGenericPlugins::<ButtonUi>::new())
 
+            // Add state blending of the button parts
+            .add_plugins(WidgetStatePlugin::<Button>::new())
+
             // Add general systems
+            .add_systems(Update, button_update_system.before(UiSystems::Compute))
             .add_systems(Update, build_component.before(UiSystems::Compute));
     }
 }
@@ -0,0 +1,315 @@
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a modal dialog is built on top of the current route.
+/// The `title`, `message` and button `text` fields are localization keys.
+///
+/// ```ignore
+/// commands.spawn((
+///     Dialog::confirm("dialog-quit-title", "dialog-quit-message"),
+///     OnDialogResult::new(DialogChoice::Confirm, |commands| { commands.add(|world: &mut World| { world.send_event(AppExit::Success); }); }),
+/// ));
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct Dialog {
+    pub title: String,
+    pub message: String,
+    pub buttons: Vec<DialogButton>,
+    /// Index of the focused button
+    pub focus: usize,
+}
+impl Dialog {
+    /// Creates a new dialog without any buttons
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Dialog { title: title.into(), message: message.into(), buttons: Vec::new(), focus: 0 }
+    }
+    /// Creates a new dialog with cancel and confirm buttons. Cancel is focused by default.
+    pub fn confirm(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Dialog::new(title, message)
+            .button("dialog-cancel", DialogChoice::Cancel)
+            .button("dialog-confirm", DialogChoice::Confirm)
+    }
+    /// Adds a button to the dialog
+    pub fn button(mut self, text: impl Into<String>, choice: DialogChoice) -> Self {
+        self.buttons.push(DialogButton { text: text.into(), choice });
+        self
+    }
+}
+
+/// Single button of the [`Dialog`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DialogButton {
+    pub text: String,
+    pub choice: DialogChoice,
+}
+
+/// Result of the [`Dialog`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogChoice {
+    Confirm,
+    #[default]
+    Cancel,
+    /// Third option, for example "Don't save"
+    Alternative,
+}
+
+/// Event that is emmited when the [`Dialog`] is resolved, right before it is despawned
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct DialogResultEvent {
+    /// The dialog entity
+    pub dialog: Entity,
+    pub choice: DialogChoice,
+}
+
+/// Add this component to the [`Dialog`] entity to run a closure when the dialog resolves with the choice
+#[derive(Component)]
+pub struct OnDialogResult {
+    choice: DialogChoice,
+    closure: Box<dyn Fn(&mut Commands) + Send + Sync>,
+}
+impl OnDialogResult {
+    pub fn new(choice: DialogChoice, closure: impl Fn(&mut Commands) + Send + Sync + 'static) -> Self {
+        OnDialogResult { choice, closure: Box::new(closure) }
+    }
+}
+
+/// Run condition that is true while no [`Dialog`] is open, use it to block input of routes beneath
+pub fn no_dialog_open(query: Query<(), With<Dialog>>) -> bool {
+    query.is_empty()
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Control struct linking the dialog buttons to the dialog
+#[derive(Component, Debug, Clone, PartialEq)]
+struct DialogControl {
+    dialog: Entity,
+    index: usize,
+}
+
+/// Spawn order of the dialog, the dialog with the highest order is the topmost one
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct DialogOrder(u32);
+
+/// System that builds the component UI
+fn build_component (mut commands: Commands, query: Query<(Entity, &Dialog), Added<Dialog>>, assets: Res<AssetServer>, mut counter: Local<u32>) {
+    for (entity, dialog) in &query {
+        *counter += 1;
+
+        // Spawn the dialog
+        commands.entity(entity).insert((
+            SpatialBundle::default(),
+            DialogOrder(*counter),
+        )).with_children(|route| {
+
+            // Spawn the master ui tree
+            route.spawn((
+                UiTreeBundle::<MainUi>::from(UiTree::new2d("Dialog")),
+                SourceFromCamera,
+            )).with_children(|ui| {
+
+                // Spawn the root div
+                let root = UiLink::<MainUi>::path("Root");
+                ui.spawn((
+                    root.clone(),
+                    UiLayout::window_full().pack::<Base>(),
+
+                    // Render the dialog on top of the route beneath
+                    UiDepthBias(50.0),
+                ));
+
+                // Spawn the backdrop that blocks the input for the route beneath
+                ui.spawn((
+                    root.add("Backdrop"),
+                    UiLayout::window_full().pack::<Base>(),
+                    UiImage2dBundle {
+                        sprite: Sprite { color: Color::BLACK.with_alpha(0.75), ..default() },
+                        ..default()
+                    },
+                    PickableBundle::default(),
+                ));
+
+                // Spawn the panel
+                let panel = root.add("Backdrop/Solid");
+                ui.spawn((
+                    panel.clone(),
+                    UiLayout::solid().size((1400.0, 600.0)).pack::<Base>(),
+                ));
+                let panel = panel.add("Panel");
+                ui.spawn((
+                    panel.clone(),
+                    UiLayout::window().pos(Rl((50.0, 50.0))).anchor(Anchor::Center).size(Rl((70.0, 60.0))).pack::<Base>(),
+                    UiImage2dBundle {
+                        texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+                        sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                        ..default()
+                    },
+                    ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), ..default() }),
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn title
+                ui.spawn((
+                    panel.add("Title"),
+                    UiLayout::window().pos(Rl((50., 12.))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_SEMIBOLD),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_YELLOW.with_alpha(1.2),
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new(&dialog.title),
+                    UiTextSize::new().size(Rh(14.0)),
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn message
+                ui.spawn((
+                    panel.add("Message"),
+                    UiLayout::window().pos(Rl((50., 42.))).anchor(Anchor::Center).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new(&dialog.message),
+                    TextEffect::typewriter(60.0),
+                    UiTextSize::new().size(Rh(9.0)),
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn button boundary
+                let list = panel.add("List");
+                ui.spawn((
+                    list.clone(),
+                    UiLayout::window().pos(Rl((50.0, 65.0))).anchor(Anchor::TopCenter).size(Rl((90.0, 20.0))).pack::<Base>(),
+                ));
+
+                // Spawn buttons
+                let count = dialog.buttons.len().max(1) as f32;
+                let gap = 4.0;
+                let size = (100.0 - gap * (count - 1.0)) / count;
+                let mut offset = 0.0;
+                for (index, button) in dialog.buttons.iter().enumerate() {
+                    let mut control = ui.spawn((
+                        list.add(format!("Button{index}")),
+                        UiLayout::window().x(Rl(offset)).size(Rl((size, 100.0))).pack::<Base>(),
+                        Button { text: button.text.clone(), selected: index == dialog.focus, ..default() },
+                        DialogControl { dialog: entity, index },
+                    ));
+
+                    // Cancelling plays the back sound
+                    if button.choice == DialogChoice::Cancel { control.insert(WidgetSounds::back()); }
+
+                    offset += gap + size;
+                }
+            });
+        });
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that moves the focus and resolves the topmost dialog from keyboard and gamepad
+fn dialog_input_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    mut query: Query<(Entity, &mut Dialog, &DialogOrder)>,
+    mut result: EventWriter<DialogResultEvent>,
+    mut sound: EventWriter<PlayWidgetSound>,
+    callbacks: Query<&OnDialogResult>,
+) {
+    let Some((entity, mut dialog, _)) = query.iter_mut().max_by_key(|(_, _, order)| **order) else { return };
+    if dialog.buttons.is_empty() { return; }
+
+    let pad = |button| gamepad.just_pressed(GamepadButton::new(Gamepad::new(0), button));
+    let len = dialog.buttons.len();
+
+    // Move the focus
+    let focus = dialog.focus;
+    if keyboard.just_pressed(KeyCode::ArrowLeft) || pad(GamepadButtonType::DPadLeft) {
+        dialog.focus = (dialog.focus + len - 1) % len;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) || keyboard.just_pressed(KeyCode::Tab) || pad(GamepadButtonType::DPadRight) {
+        dialog.focus = (dialog.focus + 1) % len;
+    }
+    if dialog.focus != focus {
+        sound.send(PlayWidgetSound { target: entity, sound: WidgetSound::Focus });
+    }
+
+    // Resolve the dialog
+    let choice = if keyboard.just_pressed(KeyCode::Enter) || pad(GamepadButtonType::South) {
+        dialog.buttons.get(dialog.focus).map(|button| button.choice)
+    } else if keyboard.just_pressed(KeyCode::Escape) || pad(GamepadButtonType::East) {
+        Some(DialogChoice::Cancel)
+    } else {
+        None
+    };
+    if let Some(choice) = choice {
+        let click = if choice == DialogChoice::Cancel { WidgetSound::Back } else { WidgetSound::Click };
+        sound.send(PlayWidgetSound { target: entity, sound: click });
+        resolve(&mut commands, entity, choice, &mut result, &callbacks);
+    }
+}
+
+/// System that resolves the dialog when one of its buttons is clicked
+fn dialog_click_system(mut commands: Commands, mut events: EventReader<UiClickEvent>, buttons: Query<&DialogControl>, query: Query<&Dialog>, mut result: EventWriter<DialogResultEvent>, callbacks: Query<&OnDialogResult>) {
+    for event in events.read() {
+        if let Ok(control) = buttons.get(event.target) {
+            if let Some(button) = query.get(control.dialog).ok().and_then(|dialog| dialog.buttons.get(control.index)) {
+                resolve(&mut commands, control.dialog, button.choice, &mut result, &callbacks);
+            }
+        }
+    }
+}
+
+/// Sends the result, runs the callbacks and despawns the dialog
+fn resolve(commands: &mut Commands, dialog: Entity, choice: DialogChoice, result: &mut EventWriter<DialogResultEvent>, callbacks: &Query<&OnDialogResult>) {
+    result.send(DialogResultEvent { dialog, choice });
+    if let Ok(callback) = callbacks.get(dialog) {
+        if callback.choice == choice { (callback.closure)(commands); }
+    }
+    commands.entity(dialog).despawn_recursive();
+}
+
+/// System that highlights the focused button
+fn dialog_focus_system(query: Query<&Dialog, Changed<Dialog>>, mut buttons: Query<(&DialogControl, &mut Button)>) {
+    for (control, mut button) in &mut buttons {
+        if let Ok(dialog) = query.get(control.dialog) {
+            let selected = control.index == dialog.focus;
+            if button.selected != selected { button.selected = selected; }
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct DialogPlugin;
+impl Plugin for DialogPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<DialogResultEvent>()
+
+            // Add general systems
+            .add_systems(Update, (dialog_input_system.run_if(console_closed), dialog_click_system.run_if(on_event::<UiClickEvent>()), dialog_focus_system).chain())
+            .add_systems(PreUpdate, build_component.before(UiSystems::Compute));
+    }
+}
@@ -1,24 +1,114 @@
 pub mod button;
 pub use button::*;
 
+pub mod dialog;
+pub use dialog::*;
+
 pub mod main_button;
 pub use main_button::*;
 
+pub mod scroll_list;
+pub use scroll_list::*;
+
+pub mod slider;
+pub use slider::*;
+
 pub mod spinner;
 pub use spinner::*;
 
-// #========================#
-// #=== COMPONENT PLUGIN ===#
+pub mod switch;
+pub use switch::*;
+
+pub mod text_effect;
+pub use text_effect::*;
+
+pub mod text_input;
+pub use text_input::*;
+
+pub mod tooltip;
+pub use tooltip::*;
+
+pub mod widget_sound;
+pub use widget_sound::*;
+
+pub mod widget_state;
+pub use widget_state::*;
+
+// #===========================#
+// #=== SHARED WIDGET TYPES ===#
 
 use bevy::prelude::*;
 
+/// Interaction state of button-like widgets, resolved from the fields of the widget component
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetState {
+    /// Idle widget that reacts to hover
+    #[default]
+    Base,
+    /// Widget is being held down
+    Pressed,
+    /// Widget is the active one in its group (for example the current tab)
+    Selected,
+    /// Widget can't be interacted with
+    Disabled,
+}
+impl WidgetState {
+    /// Resolves the state from widget flags. Disabled takes priority over pressed and pressed over selected.
+    pub fn resolve(disabled: bool, pressed: bool, selected: bool) -> Self {
+        if disabled { WidgetState::Disabled } else if pressed { WidgetState::Pressed } else if selected { WidgetState::Selected } else { WidgetState::Base }
+    }
+}
+
+
+/// Optional leading icon of button-like widgets
+#[derive(Debug, Clone, PartialEq)]
+pub enum ButtonIcon {
+    /// Whole image
+    Image(Handle<Image>),
+    /// Single tile of a texture atlas
+    Atlas {
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    },
+}
+impl ButtonIcon {
+    /// Returns the image handle of the icon
+    pub fn image(&self) -> Handle<Image> {
+        match self {
+            ButtonIcon::Image(image) => image.clone(),
+            ButtonIcon::Atlas { image, .. } => image.clone(),
+        }
+    }
+    /// Returns the texture atlas of the icon, if any
+    pub fn atlas(&self) -> Option<TextureAtlas> {
+        match self {
+            ButtonIcon::Image(_) => None,
+            ButtonIcon::Atlas { layout, index, .. } => Some(TextureAtlas { layout: layout.clone(), index: *index }),
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
 /// Plugin adding all our component logic
 pub struct ComponentPlugin;
 impl Plugin for ComponentPlugin {
     fn build(&self, app: &mut App) {
         app
             .add_plugins(ButtonPlugin)
+            .add_plugins(DialogPlugin)
             .add_plugins(MainButtonPlugin)
-            .add_plugins(SpinnerPlugin);
+            .add_plugins(ScrollListPlugin)
+            .add_plugins(SliderPlugin)
+            .add_plugins(SpinnerPlugin)
+            .add_plugins(SwitchPlugin)
+            .add_plugins(TextEffectPlugin)
+            .add_plugins(TextInputPlugin)
+            .add_plugins(TooltipPlugin)
+            .add_plugins(WidgetSoundPlugin)
+            .add_plugins(WidgetStylePlugin);
     }
 }
\ No newline at end of file
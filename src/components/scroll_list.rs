@@ -0,0 +1,468 @@
+use std::sync::Arc;
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::ecs::system::EntityCommands;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::render::camera::ScalingMode;
+use bevy::render::render_resource::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::{RenderLayers, VisibilitySystems};
+use bevy::transform::TransformSystem;
+use bevy::window::PrimaryWindow;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a vertical list with a scrollbar is built.
+/// Only the rows that fit into the list are spawned. The builder closure is called
+/// every time a row is assigned a new index, so it should insert the same set of components for every index.
+///
+/// ```ignore
+/// ScrollList::new(saves.len(), 14.0, |row, index| { row.insert(MainButton { text: saves[index].clone(), ..default() }); })
+/// ```
+#[derive(Component, Clone)]
+pub struct ScrollList {
+    /// Number of rows in the list
+    pub len: usize,
+    /// Height of a single row in % of the list height
+    pub row: f32,
+    /// Gap between rows in % of the list height
+    pub gap: f32,
+    /// Index of the focused row, the list scrolls to keep it visible
+    pub focus: Option<usize>,
+    builder: Arc<dyn Fn(&mut EntityCommands, usize) + Send + Sync>,
+}
+impl ScrollList {
+    /// Creates a new list with a default gap
+    pub fn new(len: usize, row: f32, builder: impl Fn(&mut EntityCommands, usize) + Send + Sync + 'static) -> Self {
+        ScrollList { len, row, gap: 3.0, focus: None, builder: Arc::new(builder) }
+    }
+    /// Sets the gap between rows
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+    /// Height of the whole content in % of the list height
+    pub fn content_height(&self) -> f32 {
+        (self.len as f32 * (self.row + self.gap) - self.gap).max(0.0)
+    }
+    /// Maximum scroll offset in % of the list height
+    pub fn max_scroll(&self) -> f32 {
+        (self.content_height() - 100.0).max(0.0)
+    }
+    /// Number of row entities needed to cover the list
+    fn slot_count(&self) -> usize {
+        (100.0 / (self.row + self.gap)).ceil() as usize + 1
+    }
+}
+
+/// Component on every spawned row of the [`ScrollList`]
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ScrollListRow {
+    /// The list entity
+    pub list: Entity,
+    /// Index of the row that this entity currently displays
+    pub index: usize,
+    /// If this row is the focused one
+    pub focused: bool,
+}
+
+/// Resource with the [`ScrollList`] that receives the arrow keys, dpad and stick.
+/// Hovering a list or setting its focus gives it the input focus.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct ScrollInputFocus(Option<Entity>);
+impl ScrollInputFocus {
+    /// Returns the list with the input focus
+    pub fn get(&self) -> Option<Entity> {
+        self.0
+    }
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Marker struct for the sandboxed UI
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct ScrollListUi;
+
+/// Internal state of the list
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ScrollState {
+    /// Current scroll offset in % of the list height
+    scroll: f32,
+    /// Scroll offset we are smoothly moving towards
+    target: f32,
+    /// Pooled row entities
+    slots: Vec<Entity>,
+    /// Last applied position of every slot, used to skip redundant updates
+    placed: Vec<Option<(usize, f32)>>,
+    /// Last applied scrollbar thumb offset and size
+    thumb_placed: Option<(f32, f32)>,
+    track: Entity,
+    thumb: Entity,
+    hovered: bool,
+    /// Focus the list had after the last input, a different focus was set from outside
+    seen_focus: Option<usize>,
+    /// Render layer of the rows, only the camera of the list renders it
+    layer: usize,
+    /// Node showing the rendered rows, clipped to the list
+    mask: Entity,
+    /// Image the rows are rendered into
+    image: Handle<Image>,
+}
+
+/// Camera rendering the rows of the list into its mask image
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ScrollListCamera {
+    list: Entity,
+    /// Last applied size of the list in world units
+    size: Vec2,
+}
+
+/// Returns the image the rows are rendered into, it is resized to the list once it is laid out
+fn mask_image() -> Image {
+    let size = Extent3d { width: 1, height: 1, ..default() };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba16Float,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    image
+}
+
+/// System that builds the component UI
+fn build_component (mut commands: Commands, query: Query<(Entity, &ScrollList), Added<ScrollList>>, mut images: ResMut<Assets<Image>>, mut layers: Local<usize>) {
+    for (entity, list) in &query {
+
+        let mut state = None;
+
+        // Lunex has no clipping, so the rows are rendered on their own layer into an image the size of the list
+        *layers += 1;
+        let layer = *layers;
+        let image = images.add(mask_image());
+        commands.spawn((
+            Camera2dBundle {
+                camera: Camera {
+                    order: -1,
+                    target: image.clone().into(),
+                    clear_color: ClearColorConfig::Custom(Color::NONE),
+                    hdr: true,
+                    ..default()
+                },
+                // The main camera tonemaps the mask together with the rest of the UI
+                tonemapping: Tonemapping::None,
+                ..default()
+            },
+            RenderLayers::layer(layer),
+            ScrollListCamera { list: entity, size: Vec2::ZERO },
+        ));
+
+        // This will create a private sandboxed UiTree within the entity just for the list
+        commands.entity(entity).insert(
+            UiTreeBundle::<ScrollListUi>::from(UiTree::new2d("ScrollList")),
+        ).with_children(|ui| {
+
+            // Spawn the viewport, it catches the pointer over empty space
+            ui.spawn((
+                UiLink::<ScrollListUi>::path("Viewport"),
+                UiLayout::window_full().pack::<Base>(),
+                UiZoneBundle::default(),
+            ));
+
+            // Spawn the mask showing the rendered rows
+            let mask = ui.spawn((
+                UiLink::<ScrollListUi>::path("Mask"),
+                UiLayout::window_full().pack::<Base>(),
+                UiImage2dBundle::from(image.clone()),
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn scrollbar track
+            let track = ui.spawn((
+                UiLink::<ScrollListUi>::path("Track"),
+                UiLayout::window().x(Rl(98.0)).size(Rl((2.0, 100.0))).pack::<Base>(),
+                UiImage2dBundle {
+                    sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                    ..default()
+                },
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn scrollbar thumb
+            let thumb = ui.spawn((
+                UiLink::<ScrollListUi>::path("Track/Thumb"),
+                UiLayout::window_full().pack::<Base>(),
+                UiImage2dBundle {
+                    sprite: Sprite { color: Color::BEVYPUNK_RED, ..default() },
+                    ..default()
+                },
+
+                // This makes the thumb draggable
+                PickableBundle::default(),
+                OnHoverSetCursor::new(CursorIcon::Grab),
+            )).id();
+
+            // Spawn the pooled rows
+            let mut slots = Vec::new();
+            for index in 0..list.slot_count() {
+                let mut row = ui.spawn((
+                    UiLink::<ScrollListUi>::path(format!("Viewport/Row{index}")),
+                    UiLayout::window().y(Rl(index as f32 * (list.row + list.gap))).size(Rl((100.0, list.row))).pack::<Base>(),
+                    ScrollListRow { list: entity, index, focused: list.focus == Some(index) },
+                ));
+                if index < list.len { (list.builder)(&mut row, index); }
+                slots.push(row.id());
+            }
+
+            state = Some(ScrollState { scroll: 0.0, target: 0.0, placed: vec![None; slots.len()], slots, thumb_placed: None, track, thumb, hovered: false, seen_focus: None, layer, mask, image });
+        });
+
+        if let Some(state) = state {
+            commands.entity(entity).insert(state);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// Returns true if `ancestor` is the entity itself or one of its ancestors
+fn is_descendant(entity: Entity, ancestor: Entity, parents: &Query<&Parent>) -> bool {
+    std::iter::once(entity).chain(parents.iter_ancestors(entity)).any(|entity| entity == ancestor)
+}
+
+
+/// System that tracks which list is under the pointer, hovering a list gives it the input focus
+fn scroll_hover_system(mut events: EventReader<Pointer<Move>>, parents: Query<&Parent>, mut input: ResMut<ScrollInputFocus>, mut query: Query<(Entity, &mut ScrollState)>) {
+    for event in events.read() {
+        for (entity, mut state) in &mut query {
+            let hovered = is_descendant(event.target, entity, &parents);
+            if state.hovered != hovered {
+                state.hovered = hovered;
+                if hovered { input.0 = Some(entity); }
+            }
+        }
+    }
+}
+
+/// System that scrolls the lists with the mouse wheel, gamepad stick, scrollbar and keyboard focus
+fn scroll_input_system(
+    time: Res<Time>,
+    mut wheel: EventReader<MouseWheel>,
+    mut drag: EventReader<Pointer<Drag>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepad_input: Res<Axis<GamepadAxis>>,
+    dimension: Query<&Dimension>,
+    mut input: ResMut<ScrollInputFocus>,
+    mut query: Query<(Entity, &mut ScrollList, &mut ScrollState)>,
+) {
+    // Sum the wheel in lines
+    let lines: f32 = wheel.read().map(|event| match event.unit {
+        MouseScrollUnit::Line => event.y,
+        MouseScrollUnit::Pixel => event.y / 40.0,
+    }).sum();
+    let drags: Vec<(Entity, f32)> = drag.read().map(|event| (event.target, event.delta.y)).collect();
+
+    // Pull gamepad values
+    let stick = gamepad_input.get(GamepadAxis { gamepad: Gamepad::new(0), axis_type: GamepadAxisType::RightStickY }).unwrap_or(0.0);
+    let pad = |button| gamepad_buttons.just_pressed(GamepadButton::new(Gamepad::new(0), button));
+    let step = keyboard.just_pressed(KeyCode::ArrowDown) as i32 - keyboard.just_pressed(KeyCode::ArrowUp) as i32
+        + pad(GamepadButtonType::DPadDown) as i32 - pad(GamepadButtonType::DPadUp) as i32;
+
+    // A list whose focus was set from outside takes the input focus, a despawned list passes it to any focused list
+    for (entity, list, state) in &query {
+        if list.focus.is_some() && list.focus != state.seen_focus { input.0 = Some(entity); }
+    }
+    if !input.0.is_some_and(|entity| query.contains(entity)) {
+        input.0 = query.iter().find(|(_, list, _)| list.focus.is_some()).map(|(entity, ..)| entity);
+    }
+
+    for (entity, mut list, mut state) in &mut query {
+        let stride = list.row + list.gap;
+
+        // The wheel scrolls the list under the pointer
+        if state.hovered { state.target -= lines * stride; }
+
+        // The keys, dpad and stick only control the list with the input focus
+        if input.0 == Some(entity) {
+            state.target -= stick * stride * 10.0 * time.delta_seconds();
+
+            if step != 0 && list.len > 0 {
+                let focus = list.focus.map(|focus| focus as i32 + step).unwrap_or(0).clamp(0, list.len as i32 - 1);
+                list.focus = Some(focus as usize);
+            }
+        }
+        if state.seen_focus != list.focus { state.seen_focus = list.focus; }
+
+        // Drag the scrollbar thumb
+        for (target, delta) in &drags {
+            if *target != state.thumb { continue; }
+            if let Ok(track) = dimension.get(state.track) {
+                if track.size.y > 0.0 { state.target += delta / track.size.y * list.content_height(); }
+            }
+        }
+
+        let max = list.max_scroll();
+        state.target = state.target.clamp(0.0, max);
+    }
+}
+
+/// System that smoothly scrolls the lists, keeps focus visible and reassigns the pooled rows
+fn scroll_update_system(mut commands: Commands, time: Res<Time>, mut query: Query<(&ScrollList, &mut ScrollState)>, mut rows: Query<(&mut ScrollListRow, Option<&mut Visibility>)>) {
+    for (list, mut state) in &mut query {
+        let stride = list.row + list.gap;
+        let max = list.max_scroll();
+
+        // Follow the focus
+        if let Some(focus) = list.focus {
+            let top = focus as f32 * stride;
+            if top < state.target { state.target = top; }
+            if top + list.row > state.target + 100.0 { state.target = top + list.row - 100.0; }
+            state.target = state.target.clamp(0.0, max);
+        }
+
+        // Smoothly move towards the target
+        let delta = (state.target - state.scroll) * (time.delta_seconds() * 12.0).min(1.0);
+        if delta.abs() > 0.001 { state.scroll += delta; } else if state.scroll != state.target { state.scroll = state.target; }
+
+        // Assign indexes to the pooled rows
+        let count = state.slots.len();
+        if count == 0 { continue; }
+        let first = (state.scroll / stride).floor().max(0.0) as usize;
+        let width = if max > 0.0 { 96.0 } else { 100.0 };
+        for index in first..first + count {
+            let slot = index % count;
+            let entity = state.slots[slot];
+            let y = index as f32 * stride - state.scroll;
+
+            // Rows crossing the edge are clipped by the mask, only rows fully outside are hidden
+            let visible = index < list.len && y < 100.0 && y + list.row > 0.0;
+            let wanted = if visible { Visibility::Inherited } else { Visibility::Hidden };
+
+            if let Ok((mut row, visibility)) = rows.get_mut(entity) {
+                let focused = list.focus == Some(index);
+                if row.index != index && index < list.len {
+                    row.index = index;
+                    (list.builder)(&mut commands.entity(entity), index);
+                }
+                if row.focused != focused { row.focused = focused; }
+
+                // Checked every frame, the widget built in the row can reset the visibility of the row
+                match visibility {
+                    Some(mut visibility) => if *visibility != wanted { *visibility = wanted; },
+                    None => { commands.entity(entity).insert(wanted); },
+                }
+            }
+
+            if state.placed[slot] != Some((index, y)) {
+                state.placed[slot] = Some((index, y));
+                commands.entity(entity).insert(UiLayout::window().y(Rl(y)).size(Rl((width, list.row))).pack::<Base>());
+            }
+        }
+
+        // Update the scrollbar
+        let thumb = if list.content_height() > 0.0 { (100.0 / list.content_height() * 100.0).min(100.0) } else { 100.0 };
+        let offset = if max > 0.0 { state.scroll / max * (100.0 - thumb) } else { 0.0 };
+        if state.thumb_placed != Some((offset, thumb)) {
+            state.thumb_placed = Some((offset, thumb));
+            commands.entity(state.thumb).insert(UiLayout::window().y(Rl(offset)).size(Rl((100.0, thumb))).pack::<Base>());
+            commands.entity(state.track).insert(if max > 0.0 { Visibility::Inherited } else { Visibility::Hidden });
+        }
+    }
+}
+
+
+// #================#
+// #=== CLIPPING ===#
+
+/// System that moves the rows and everything built in them onto the render layer of their list
+fn scroll_layer_system(mut commands: Commands, query: Query<&ScrollState>, children: Query<&Children>, layers: Query<(), With<RenderLayers>>) {
+    for state in &query {
+        for slot in &state.slots {
+            for entity in std::iter::once(*slot).chain(children.iter_descendants(*slot)) {
+                if !layers.contains(entity) { commands.entity(entity).insert(RenderLayers::layer(state.layer)); }
+            }
+        }
+    }
+}
+
+/// System that fits the camera and the image of every list to where the list is on the screen
+#[allow(clippy::type_complexity)]
+fn scroll_mask_system(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    main_camera: Query<(&Camera, &GlobalTransform, &OrthographicProjection), (With<MainUi>, Without<ScrollListCamera>)>,
+    masks: Query<(&Sprite, &GlobalTransform), Without<Camera>>,
+    lists: Query<&ScrollState>,
+    mut cameras: Query<(Entity, &mut ScrollListCamera, &mut Transform, &mut GlobalTransform, &mut OrthographicProjection)>,
+) {
+    let Ok(window) = windows.get_single() else { return };
+    let Ok((main, main_transform, main_projection)) = main_camera.get_single() else { return };
+
+    for (entity, mut camera, mut transform, mut global, mut projection) in &mut cameras {
+        let Ok(state) = lists.get(camera.list) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+
+        // World rect of the list, taken from the mask sprite laid out by Lunex
+        let Ok((sprite, mask)) = masks.get(state.mask) else { continue };
+        let Some(size) = sprite.custom_size else { continue };
+        let (scale, _, translation) = mask.to_scale_rotation_translation();
+        let size = size * scale.truncate();
+        let center = translation.truncate() - sprite.anchor.as_vec() * size;
+
+        // Render exactly the list area with the same depth range as the main camera
+        transform.translation = center.extend(main_transform.translation().z);
+        *global = GlobalTransform::from(*transform);
+        if camera.size != size {
+            camera.size = size;
+            *projection = OrthographicProjection { scaling_mode: ScalingMode::Fixed { width: size.x, height: size.y }, ..main_projection.clone() };
+        }
+
+        // Match the image to the pixels the list covers on the screen
+        let corners = (main.world_to_viewport(main_transform, (center - size / 2.0).extend(0.0)), main.world_to_viewport(main_transform, (center + size / 2.0).extend(0.0)));
+        let (Some(min), Some(max)) = corners else { continue };
+        let pixels = ((max - min).abs() * window.scale_factor()).round().max(Vec2::ONE).as_uvec2();
+        if images.get(&state.image).is_some_and(|image| image.size() != pixels) {
+            if let Some(image) = images.get_mut(&state.image) {
+                image.resize(Extent3d { width: pixels.x, height: pixels.y, ..default() });
+            }
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct ScrollListPlugin;
+impl Plugin for ScrollListPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Add Lunex plugins for our sandboxed UI
+            .add_plugins(UiGenericPlugins::<ScrollListUi>::new())
+            .init_resource::<ScrollInputFocus>()
+
+            // Add general systems
+            .add_systems(Update, (scroll_hover_system, scroll_input_system.run_if(no_dialog_open).run_if(console_closed), scroll_update_system).chain().before(UiSystems::Compute))
+            .add_systems(Update, build_component.before(UiSystems::Compute))
+            .add_systems(PostUpdate, (scroll_layer_system.before(VisibilitySystems::CheckVisibility), scroll_mask_system.after(TransformSystem::TransformPropagate).before(VisibilitySystems::UpdateFrusta)));
+    }
+}
@@ -0,0 +1,394 @@
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a UI system is built. Dragging the track changes the value.
+/// The `text` field is a localization key. Changing any field after spawn updates the slider.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Slider {
+    pub text: String,
+    pub min: f32,
+    pub max: f32,
+    /// The value snaps to multiples of the step, zero disables snapping
+    pub step: f32,
+    pub value: f32,
+    /// Disabled slider can't be dragged
+    pub disabled: bool,
+}
+impl Default for Slider {
+    fn default() -> Self {
+        Slider { text: String::new(), min: 0.0, max: 1.0, step: 0.0, value: 0.0, disabled: false }
+    }
+}
+impl Slider {
+    pub fn new(text: impl Into<String>, min: f32, max: f32) -> Self {
+        Slider { text: text.into(), min, max, value: min, ..default() }
+    }
+    /// Sets the snapping step
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+    /// Sets the value
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = self.snap(value);
+        self
+    }
+    /// Clamps the value into the range and snaps it to the step
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = if self.step > 0.0 { self.min + ((value - self.min) / self.step).round() * self.step } else { value };
+        value.clamp(self.min, self.max)
+    }
+    /// Position of the value in the range from 0 to 1
+    pub fn fraction(&self) -> f32 {
+        if self.max > self.min { ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0) } else { 0.0 }
+    }
+    /// Returns the resolved state of the slider
+    pub fn state(&self) -> WidgetState {
+        WidgetState::resolve(self.disabled, false, false)
+    }
+}
+
+/// Event that is emmited when the user drags a [`Slider`]
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SliderChangeEvent {
+    /// The slider entity
+    pub target: Entity,
+    pub value: f32,
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Marker struct for the sandboxed UI
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct SliderUi;
+
+/// Entities of the slider parts that change with the slider state
+#[derive(Component, Debug, Clone, PartialEq)]
+struct SliderParts {
+    text: Entity,
+    value: Entity,
+    track: Entity,
+    fill: Entity,
+    head: Entity,
+    zone: Entity,
+}
+
+/// Unsnapped value of the slider while it is being dragged
+#[derive(Component, Debug, Clone, PartialEq)]
+struct SliderDrag(f32);
+
+/// Returns base and hover color of the slider for the given state
+fn slider_colors(state: WidgetState) -> (Color, Color) {
+    match state {
+        WidgetState::Disabled => (Color::BEVYPUNK_RED_DIM.with_alpha(0.5), Color::BEVYPUNK_RED_DIM.with_alpha(0.5)),
+        _ => (Color::BEVYPUNK_RED, Color::BEVYPUNK_YELLOW),
+    }
+}
+
+/// Returns the layout of the filled part of the track
+fn fill_layout(slider: &Slider) -> UiLayout<Base> {
+    UiLayout::window().size(Rl((slider.fraction() * 100.0, 100.0))).pack::<Base>()
+}
+
+/// Returns the layout of the slider head, which sits at the end of the filled track
+fn head_layout(slider: &Slider) -> UiLayout<Base> {
+    UiLayout::window().pos(Rl((slider.fraction() * 100.0, 50.0))).anchor(Anchor::Center).size(Rh(400.0)).pack::<Base>()
+}
+
+/// Formats the value with the precision of the step
+fn format_value(slider: &Slider) -> String {
+    let decimals = if slider.step >= 1.0 { 0 } else if slider.step >= 0.1 { 1 } else { 2 };
+    format!("{:.*}", decimals, slider.value)
+}
+
+/// System that builds the component UI
+fn build_component (mut commands: Commands, query: Query<(Entity, &Slider), Added<Slider>>, assets: Res<AssetServer>) {
+    for (entity, slider) in &query {
+
+        let (base, hover) = slider_colors(slider.state());
+        let mut parts = None;
+
+        // This will create a private sandboxed UiTree within the entity just for the slider
+        commands.entity(entity).insert(
+            UiTreeBundle::<SliderUi>::from(UiTree::new2d("Slider")),
+        ).with_children(|ui| {
+
+            // Spawn slider image
+            ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Give it a background image
+                UiImage2dBundle {
+                    texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+                    sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                    ..default()
+                },
+
+                // Make the background scalable
+                ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), ..default() }),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+            ));
+
+            // Spawn slider text
+            let text = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image/Text"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((5., 50.))).anchor(Anchor::CenterLeft).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section("",
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_SEMIBOLD),
+                            font_size: 60.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Text is looked up from the string table
+                LocalizedText::new(&slider.text),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(hover),
+            )).id();
+
+            // Spawn slider value
+            let value = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image/Value"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((95., 50.))).anchor(Anchor::CenterRight).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section(format_value(slider),
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_MEDIUM),
+                            font_size: 60.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(hover),
+            )).id();
+
+            // Spawn slider track
+            let track = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image/Track"),
+
+                // Place the track between the text and the value
+                UiLayout::window().pos(Rl((45., 50.))).anchor(Anchor::CenterLeft).size(Rl((35., 10.))).pack::<Base>(),
+
+                // Give it a color
+                UiImage2dBundle::default(),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This will set the color to dim red
+                UiColor::<Base>::new(Color::BEVYPUNK_RED.with_alpha(0.3)),
+            )).id();
+
+            // Spawn slider fill
+            let fill = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image/Track/Fill"),
+
+                // Add layout
+                fill_layout(slider),
+
+                // Give it a color
+                UiImage2dBundle::default(),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(hover),
+            )).id();
+
+            // Spawn slider head
+            let head = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control/Image/Track/Head"),
+
+                // Add layout
+                head_layout(slider),
+
+                // Give it an image
+                UiImage2dBundle::from(assets.load(PreLoader::SWITCH_HEAD)),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(hover),
+            )).id();
+
+            // Spawn slider hover-zone
+            let zone = ui.spawn((
+                // Link this widget
+                UiLink::<SliderUi>::path("Control"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Make this spacial & clickable entity
+                UiZoneBundle::default(),
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().forward_speed(5.0).backward_speed(1.0),
+
+                // This will pipe this hover data to the specified entities
+                UiAnimatorPipe::<Hover>::new(vec![text, value, fill, head]),
+
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
+            )).id();
+
+            parts = Some(SliderParts { text, value, track, fill, head, zone });
+        });
+
+        // Store the parts so we can update them later
+        if let Some(parts) = parts {
+            commands.entity(entity).insert(parts);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that changes the value when the slider is dragged
+fn slider_drag_system(
+    mut commands: Commands,
+    mut start: EventReader<Pointer<DragStart>>,
+    mut drag: EventReader<Pointer<Drag>>,
+    mut change: EventWriter<SliderChangeEvent>,
+    dimension: Query<&Dimension>,
+    mut query: Query<(Entity, &mut Slider, &SliderParts, Option<&mut SliderDrag>)>,
+) {
+    // Remember the unsnapped value, so small drags add up
+    for event in start.read() {
+        for (entity, slider, parts, _) in &query {
+            if parts.zone == event.target { commands.entity(entity).insert(SliderDrag(slider.value)); }
+        }
+    }
+
+    for event in drag.read() {
+        for (entity, mut slider, parts, raw) in &mut query {
+            if parts.zone != event.target || slider.disabled { continue; }
+            let Some(mut raw) = raw else { continue };
+            let Ok(track) = dimension.get(parts.track) else { continue };
+            if track.size.x <= 0.0 { continue; }
+
+            raw.0 += event.delta.x / track.size.x * (slider.max - slider.min);
+            let value = slider.snap(raw.0);
+            if slider.value != value {
+                slider.value = value;
+                change.send(SliderChangeEvent { target: entity, value });
+            }
+        }
+    }
+}
+
+/// System that will update the slider parts when the component changes
+fn slider_update_system(mut commands: Commands, query: Query<(&Slider, &SliderParts), Or<(Changed<Slider>, Added<SliderParts>)>>, mut localized: Query<&mut LocalizedText>, mut text: Query<&mut Text, Without<LocalizedText>>) {
+    for (slider, parts) in &query {
+
+        // Update the label
+        if let Ok(mut text) = localized.get_mut(parts.text) {
+            if text.key != slider.text { text.key = slider.text.clone(); }
+        }
+
+        // Update the value
+        if let Ok(mut text) = text.get_mut(parts.value) {
+            let value = format_value(slider);
+            if text.sections[0].value != value { text.sections[0].value = value; }
+        }
+
+        // Update the colors and layout
+        let (base, hover) = slider_colors(slider.state());
+        for part in [parts.text, parts.value, parts.fill, parts.head] {
+            commands.entity(part).insert((UiColor::<Base>::new(base), UiColor::<Hover>::new(hover)));
+        }
+        commands.entity(parts.fill).insert(fill_layout(slider));
+        commands.entity(parts.head).insert(head_layout(slider));
+
+        // Disabled slider does not change the cursor
+        if slider.disabled {
+            commands.entity(parts.zone).remove::<OnHoverSetCursor>();
+        } else {
+            // This will change cursor icon on mouse hover
+            commands.entity(parts.zone).insert(OnHoverSetCursor::new(CursorIcon::Grab));
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct SliderPlugin;
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Add Lunex plugins for our sandboxed UI
+            .add_plugins(UiGenericPlugins::<SliderUi>::new())
+
+            // Add general systems
+            .add_event::<SliderChangeEvent>()
+            .add_systems(Update, (slider_drag_system, slider_update_system).chain().before(UiSystems::Compute))
+            .add_systems(Update, build_component.before(UiSystems::Compute));
+    }
+}
@@ -4,12 +4,92 @@ use crate::*;
 // #=========================#
 // #=== EXPOSED COMPONENT ===#
 
-/// When this component is added, a UI system is built
+/// When this component is added, a UI system is built.
+/// The `name` field is a localization key. Changing any field after spawn re-renders the spinner.
 #[derive(Component, Debug, Default, Clone, PartialEq)]
 pub struct Spinner {
     pub name: String,
     pub index: usize,
-    pub options: Vec<String>,
+    pub options: Vec<SpinnerOption>,
+    /// If the spinner should jump to the other end when stepping past the first or last option
+    pub wrap: bool,
+}
+impl Spinner {
+    /// Creates a new wrapping spinner with the first option selected
+    pub fn new(name: impl Into<String>, options: Vec<SpinnerOption>) -> Self {
+        Spinner { name: name.into(), index: 0, options, wrap: true }
+    }
+    /// Sets the selected option index
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+    /// Stops the spinner from wrapping around at the ends
+    pub fn no_wrap(mut self) -> Self {
+        self.wrap = false;
+        self
+    }
+    /// Returns the currently selected option
+    pub fn selected(&self) -> Option<&SpinnerOption> {
+        self.options.get(self.index)
+    }
+    /// Selects the option with the given id, returns false if there is no such option
+    pub fn select(&mut self, id: &str) -> bool {
+        if let Some(index) = self.options.iter().position(|option| option.id == id) {
+            self.index = index;
+            return true;
+        }
+        false
+    }
+    /// Returns the index of the next enabled option in the given direction, skipping disabled options.
+    /// An index past the last option steps from the last option.
+    pub fn step(&self, forward: bool) -> Option<usize> {
+        let len = self.options.len();
+        if len == 0 { return None; }
+        let mut index = self.index.min(len - 1);
+        for _ in 1..len {
+            index = match (forward, index) {
+                (true, i) if i + 1 >= len => if self.wrap { 0 } else { return None },
+                (true, i) => i + 1,
+                (false, 0) => if self.wrap { len - 1 } else { return None },
+                (false, i) => i - 1,
+            };
+            if !self.options[index].disabled { return Some(index); }
+        }
+        None
+    }
+}
+
+/// Single option of the [`Spinner`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SpinnerOption {
+    /// Stable identifier that is sent with the change event
+    pub id: String,
+    /// Localization key of the displayed label
+    pub label: String,
+    /// Disabled options are skipped when stepping through the spinner
+    pub disabled: bool,
+}
+impl SpinnerOption {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        SpinnerOption { id: id.into(), label: label.into(), disabled: false }
+    }
+    /// Marks this option as disabled
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// Event that is emmited when the user changes the selected option of a [`Spinner`]
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SpinnerChangeEvent {
+    /// The spinner entity
+    pub target: Entity,
+    /// Index of the newly selected option
+    pub index: usize,
+    /// Id of the newly selected option
+    pub id: String,
 }
 
 // #===============================#
@@ -26,6 +106,10 @@ struct SpinnerControl {
     chevron_right: Entity
 }
 
+/// Marker struct for the spinner name text
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct SpinnerName;
+
 /// System that builds the component UI
 fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Added<Spinner>>, assets: Res<AssetServer>) {
     for (entity, spinner) in &query {
@@ -93,7 +177,7 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
 
                 // Add text
                 UiText2dBundle {
-                    text: Text::from_section(spinner.options[spinner.index].clone(),
+                    text: Text::from_section("",
                         TextStyle {
                             font: assets.load(PreLoader::FONT_MEDIUM),
                             font_size: 60.0,
@@ -102,6 +186,9 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
                     ..default()
                 },
 
+                // Text is looked up from the string table
+                LocalizedText::new(spinner.selected().map(|option| option.label.clone()).unwrap_or_default()),
+
                 // Spinner control
                 SpinnerControl { chevron_left, chevron_right }
             ));
@@ -116,7 +203,7 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
 
                 // Add text
                 UiText2dBundle {
-                    text: Text::from_section(spinner.name.clone(),
+                    text: Text::from_section("",
                         TextStyle {
                             font: assets.load(PreLoader::FONT_SEMIBOLD),
                             font_size: 60.0,
@@ -124,6 +211,12 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
                         }),
                     ..default()
                 },
+
+                // Text is looked up from the string table
+                LocalizedText::new(spinner.name.clone()),
+
+                // Spinner name
+                SpinnerName,
             ));
 
             // Spawn spinner image
@@ -174,8 +267,8 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
                 // This will change cursor icon on mouse hover
                 OnHoverSetCursor::new(CursorIcon::Pointer),
 
-                // Play sound on hover event
-                OnHoverPlaySound::new(assets.load(PreLoader::SFX_UI)),
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
 
                 // This will set hover color to yellow
                 UiColor::<Hover>::new(Color::BEVYPUNK_YELLOW),
@@ -213,8 +306,8 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
                 // This will change cursor icon on mouse hover
                 OnHoverSetCursor::new(CursorIcon::Pointer),
 
-                // Play sound on hover event
-                OnHoverPlaySound::new(assets.load(PreLoader::SFX_UI)),
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
 
                 // This will set hover color to yellow
                 UiColor::<Hover>::new(Color::BEVYPUNK_YELLOW),
@@ -232,20 +325,18 @@ fn build_component (mut commands: Commands, query: Query<(Entity, &Spinner), Add
 // #=== INTERACTIVITY ===#
 
 /// System that will react to chevron presses
-fn spinner_change_system(mut events: EventReader<UiClickEvent>, mut change: EventWriter<UiChangeEvent>, mut query: Query<(&mut Spinner, &Children, Entity)>, mut text: Query<(&SpinnerControl, &mut Text)>) {
+fn spinner_change_system(mut events: EventReader<UiClickEvent>, mut change: EventWriter<SpinnerChangeEvent>, mut sound: EventWriter<PlayWidgetSound>, mut query: Query<(&mut Spinner, &Children, Entity)>, control: Query<&SpinnerControl>) {
     for event in events.read() {
         for (mut spinner, children, entity) in &mut query {
             for child in children {
-                if let Ok((spinner_control, mut text)) = text.get_mut(*child) {
-                    if spinner_control.chevron_left == event.target  {
-                        if spinner.index == 0 { spinner.index = spinner.options.len() - 1 } else { spinner.index -= 1 }
-                        text.sections[0].value = spinner.options[spinner.index].clone();
-                        change.send(UiChangeEvent { target: entity, value: spinner.options[spinner.index].clone() });
-                    }
-                    if spinner_control.chevron_right == event.target {
-                        if spinner.index + 1 == spinner.options.len() { spinner.index = 0 } else { spinner.index += 1 }
-                        text.sections[0].value = spinner.options[spinner.index].clone();
-                        change.send(UiChangeEvent { target: entity, value: spinner.options[spinner.index].clone() });
+                if let Ok(spinner_control) = control.get(*child) {
+                    let forward = if spinner_control.chevron_left == event.target { false } else if spinner_control.chevron_right == event.target { true } else { continue };
+                    if let Some(index) = spinner.step(forward) {
+                        spinner.index = index;
+                        change.send(SpinnerChangeEvent { target: entity, index, id: spinner.options[index].id.clone() });
+                    } else {
+                        // Stepping past the end of a spinner that does not wrap
+                        sound.send(PlayWidgetSound { target: entity, sound: WidgetSound::Denied });
                     }
                 }
             }
@@ -253,6 +344,21 @@ fn spinner_change_system(mut events: EventReader<UiClickEvent>, mut change: Even
     }
 }
 
+/// System that will re-render the spinner when the component changes
+fn spinner_render_system(query: Query<(&Spinner, &Children), Changed<Spinner>>, mut text: Query<&mut LocalizedText, With<SpinnerControl>>, mut name: Query<&mut LocalizedText, (With<SpinnerName>, Without<SpinnerControl>)>) {
+    for (spinner, children) in &query {
+        for child in children {
+            if let Ok(mut text) = text.get_mut(*child) {
+                let label = spinner.selected().map(|option| option.label.as_str()).unwrap_or_default();
+                if text.key != label { text.key = label.into(); }
+            }
+            if let Ok(mut text) = name.get_mut(*child) {
+                if text.key != spinner.name { text.key = spinner.name.clone(); }
+            }
+        }
+    }
+}
+
 
 // #========================#
 // #=== COMPONENT PLUGIN ===#
@@ -266,7 +372,27 @@ impl Plugin for SpinnerPlugin {
             .add_plugins(UiGenericPlugins::<SpinnerUi>::new())
 
             // Add general systems
+            .add_event::<SpinnerChangeEvent>()
             .add_systems(Update, spinner_change_system.run_if(on_event::<UiClickEvent>()))
+            .add_systems(Update, spinner_render_system.after(spinner_change_system).before(localized_text_system))
             .add_systems(Update, build_component.before(UiSystems::Compute));
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spinner(index: usize) -> Spinner {
+        Spinner::new("test", vec![SpinnerOption::new("a", "a"), SpinnerOption::new("b", "b"), SpinnerOption::new("c", "c")]).with_index(index)
+    }
+
+    #[test]
+    fn step_from_out_of_range_index() {
+        assert_eq!(spinner(10).step(false), Some(1));
+        assert_eq!(spinner(10).step(true), Some(0));
+        assert_eq!(spinner(10).no_wrap().step(true), None);
+        assert_eq!(Spinner::new("test", Vec::new()).step(true), None);
+    }
+}
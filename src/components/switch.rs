@@ -0,0 +1,335 @@
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a UI system is built. Clicking the switch flips it.
+/// The `text` field is a localization key. Changing any field after spawn updates the switch.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct Switch {
+    pub text: String,
+    /// Optional small line under the label. Displayed as is.
+    pub subtitle: Option<String>,
+    pub on: bool,
+    /// Disabled switch can't be flipped
+    pub disabled: bool,
+}
+impl Switch {
+    /// Returns the resolved state of the switch
+    pub fn state(&self) -> WidgetState {
+        WidgetState::resolve(self.disabled, false, self.on)
+    }
+}
+
+/// Event that is emmited when the user flips a [`Switch`]
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SwitchChangeEvent {
+    /// The switch entity
+    pub target: Entity,
+    pub on: bool,
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Marker struct for the sandboxed UI
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct SwitchUi;
+
+/// Entities of the switch parts that change with the switch state
+#[derive(Component, Debug, Clone, PartialEq)]
+struct SwitchParts {
+    text: Entity,
+    subtitle: Entity,
+    base: Entity,
+    head: Entity,
+    zone: Entity,
+}
+
+/// Returns base and hover color of the toggle for the given state
+fn toggle_colors(state: WidgetState) -> (Color, Color) {
+    match state {
+        WidgetState::Selected => (Color::BEVYPUNK_YELLOW.with_alpha(1.2), Color::BEVYPUNK_YELLOW.with_alpha(1.6)),
+        WidgetState::Disabled => (Color::BEVYPUNK_RED_DIM.with_alpha(0.5), Color::BEVYPUNK_RED_DIM.with_alpha(0.5)),
+        _ => (Color::BEVYPUNK_RED, Color::BEVYPUNK_YELLOW),
+    }
+}
+
+/// Returns base and hover color of the label for the given state
+fn text_colors(state: WidgetState) -> (Color, Color) {
+    match state {
+        WidgetState::Disabled => (Color::BEVYPUNK_RED_DIM.with_alpha(0.5), Color::BEVYPUNK_RED_DIM.with_alpha(0.5)),
+        _ => (Color::BEVYPUNK_RED, Color::BEVYPUNK_YELLOW),
+    }
+}
+
+/// Returns the layout of the toggle head, which slides to the right when the switch is on
+fn head_layout(switch: &Switch) -> UiLayout<Base> {
+    let x = if switch.on { 75.0 } else { 25.0 };
+    UiLayout::window().pos(Rl((x, 50.0))).anchor(Anchor::Center).size(Rh(80.0)).pack::<Base>()
+}
+
+/// Returns the layout of the label, which moves up to make space for the subtitle
+fn text_layout(switch: &Switch) -> UiLayout<Base> {
+    let y = if switch.subtitle.is_some() { 36.0 } else { 50.0 };
+    UiLayout::window().pos(Rl((5., y))).anchor(Anchor::CenterLeft).pack::<Base>()
+}
+
+/// System that builds the component UI
+fn build_component (mut commands: Commands, query: Query<(Entity, &Switch), Added<Switch>>, assets: Res<AssetServer>) {
+    for (entity, switch) in &query {
+
+        let (toggle_base, toggle_hover) = toggle_colors(switch.state());
+        let (text_base, text_hover) = text_colors(switch.state());
+        let mut parts = None;
+
+        // This will create a private sandboxed UiTree within the entity just for the switch
+        commands.entity(entity).insert(
+            UiTreeBundle::<SwitchUi>::from(UiTree::new2d("Switch")),
+        ).with_children(|ui| {
+
+            // Spawn switch image
+            ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control/Image"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Give it a background image
+                UiImage2dBundle {
+                    texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+                    sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                    ..default()
+                },
+
+                // Make the background scalable
+                ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), ..default() }),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+            ));
+
+            // Spawn switch text
+            let text = ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control/Image/Text"),
+
+                // Add layout
+                text_layout(switch),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section("",
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_SEMIBOLD),
+                            font_size: 60.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Text is looked up from the string table
+                LocalizedText::new(&switch.text),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
+            )).id();
+
+            // Spawn switch subtitle
+            let subtitle = ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control/Image/Subtitle"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((5., 72.))).anchor(Anchor::CenterLeft).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section(switch.subtitle.clone().unwrap_or_default(),
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_REGULAR),
+                            font_size: 36.0,
+                            ..default()
+                        }),
+                    ..default()
+                },
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(text_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(text_hover),
+            )).id();
+
+            // Spawn switch toggle base
+            let base = ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control/Image/Toggle"),
+
+                // Align the toggle to the right edge, the image is twice as wide as tall
+                UiLayout::window().pos((Rl(100.0) - Rh(20.0), Rl(50.0))).anchor(Anchor::CenterRight).size((Rh(90.0), Rh(45.0))).pack::<Base>(),
+
+                // Give it an image
+                UiImage2dBundle::from(assets.load(PreLoader::SWITCH_BASE)),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(toggle_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(toggle_hover),
+            )).id();
+
+            // Spawn switch toggle head
+            let head = ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control/Image/Toggle/Head"),
+
+                // Add layout
+                head_layout(switch),
+
+                // Give it an image
+                UiImage2dBundle::from(assets.load(PreLoader::SWITCH_HEAD)),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().receiver(true),
+
+                // This will set the base color (red by default)
+                UiColor::<Base>::new(toggle_base),
+
+                // This will set hover color (yellow by default)
+                UiColor::<Hover>::new(toggle_hover),
+            )).id();
+
+            // Spawn switch hover-zone
+            let zone = ui.spawn((
+                // Link this widget
+                UiLink::<SwitchUi>::path("Control"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Make this spacial & clickable entity
+                UiZoneBundle::default(),
+
+                // This is required to control our hover animation
+                UiAnimator::<Hover>::new().forward_speed(5.0).backward_speed(1.0),
+
+                // This will pipe this hover data to the specified entities
+                UiAnimatorPipe::<Hover>::new(vec![text, subtitle, base, head]),
+
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
+            )).id();
+
+            parts = Some(SwitchParts { text, subtitle, base, head, zone });
+        });
+
+        // Store the parts so we can update them later
+        if let Some(parts) = parts {
+            commands.entity(entity).insert(parts);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that will flip the switch when clicked
+fn switch_toggle_system(mut events: EventReader<UiClickEvent>, mut change: EventWriter<SwitchChangeEvent>, mut query: Query<&mut Switch>) {
+    for event in events.read() {
+        if let Ok(mut switch) = query.get_mut(event.target) {
+            if switch.disabled { continue; }
+            switch.on = !switch.on;
+            change.send(SwitchChangeEvent { target: event.target, on: switch.on });
+        }
+    }
+}
+
+/// System that will update the switch parts when the component changes
+fn switch_update_system(mut commands: Commands, query: Query<(Entity, &Switch, &SwitchParts), Or<(Changed<Switch>, Added<SwitchParts>)>>, mut localized: Query<&mut LocalizedText>, mut text: Query<&mut Text, Without<LocalizedText>>) {
+    for (entity, switch, parts) in &query {
+
+        // Update the label
+        if let Ok(mut text) = localized.get_mut(parts.text) {
+            if text.key != switch.text { text.key = switch.text.clone(); }
+        }
+
+        // Update the subtitle
+        if let Ok(mut text) = text.get_mut(parts.subtitle) {
+            let value = switch.subtitle.clone().unwrap_or_default();
+            if text.sections[0].value != value { text.sections[0].value = value; }
+        }
+
+        // Update the colors and layout
+        let (base, hover) = toggle_colors(switch.state());
+        for part in [parts.base, parts.head] {
+            commands.entity(part).insert((UiColor::<Base>::new(base), UiColor::<Hover>::new(hover)));
+        }
+        let (base, hover) = text_colors(switch.state());
+        for part in [parts.text, parts.subtitle] {
+            commands.entity(part).insert((UiColor::<Base>::new(base), UiColor::<Hover>::new(hover)));
+        }
+        commands.entity(parts.head).insert(head_layout(switch));
+        commands.entity(parts.text).insert(text_layout(switch));
+
+        // Disabled switch does not emit clicks or change the cursor
+        if switch.disabled {
+            commands.entity(parts.zone).remove::<(OnHoverSetCursor, UiClickEmitter)>();
+        } else {
+            commands.entity(parts.zone).insert((
+                // This will change cursor icon on mouse hover
+                OnHoverSetCursor::new(CursorIcon::Pointer),
+
+                // If we click on this hover zone, it will emmit UiClick event from parent entity
+                UiClickEmitter::new(entity),
+            ));
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct SwitchPlugin;
+impl Plugin for SwitchPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Add Lunex plugins for our sandboxed UI
+            .add_plugins(UiGenericPlugins::<SwitchUi>::new())
+
+            // Add general systems
+            .add_event::<SwitchChangeEvent>()
+            .add_systems(Update, (switch_toggle_system.run_if(on_event::<UiClickEvent>()), switch_update_system).chain().before(UiSystems::Compute))
+            .add_systems(Update, build_component.before(UiSystems::Compute));
+    }
+}
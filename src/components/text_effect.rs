@@ -0,0 +1,217 @@
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When a [`TextEffect`] plays
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextEffectTrigger {
+    /// Plays once, as soon as the text is not empty
+    #[default]
+    Spawn,
+    /// Plays every time the `Hover` animator of the text starts hovering
+    Hover,
+    /// Plays only on [`PlayTextEffect`]
+    Event,
+}
+
+/// What a [`TextEffect`] does with the text
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextEffectKind {
+    /// Types the characters out one by one, optionally playing the sound for every character
+    Typewriter { sound: Option<Handle<AudioSource>> },
+    /// Cycles random glyphs that settle into the text from left to right
+    Decode,
+    /// Briefly offsets and recolors slices of the text
+    Glitch,
+}
+
+/// Add this component to a text node to animate its first text section.
+/// It works together with [`LocalizedText`], a text changed during the effect is picked up by the effect.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TextEffect {
+    pub kind: TextEffectKind,
+    pub trigger: TextEffectTrigger,
+    /// Characters per second of the typewriter and decode, seconds of the glitch
+    pub speed: f32,
+    /// Seconds since the effect started, none if it is not playing
+    elapsed: Option<f32>,
+    /// The full text
+    source: String,
+    /// Text the effect wrote into the section last time
+    shown: String,
+    hovered: bool,
+    played: bool,
+}
+impl TextEffect {
+    fn new(kind: TextEffectKind, speed: f32) -> Self {
+        TextEffect { kind, trigger: TextEffectTrigger::Spawn, speed, elapsed: None, source: String::new(), shown: String::new(), hovered: false, played: false }
+    }
+    /// Types out the given characters per second
+    pub fn typewriter(speed: f32) -> Self {
+        TextEffect::new(TextEffectKind::Typewriter { sound: None }, speed)
+    }
+    /// Decodes the given characters per second
+    pub fn decode(speed: f32) -> Self {
+        TextEffect::new(TextEffectKind::Decode, speed)
+    }
+    /// Glitches for the given seconds
+    pub fn glitch(duration: f32) -> Self {
+        TextEffect::new(TextEffectKind::Glitch, duration)
+    }
+    /// Sets when the effect plays
+    pub fn on(mut self, trigger: TextEffectTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+    /// Sets the sound of every typed character, only used by the typewriter
+    pub fn sound(mut self, sound: Handle<AudioSource>) -> Self {
+        if let TextEffectKind::Typewriter { sound: current } = &mut self.kind { *current = Some(sound); }
+        self
+    }
+    /// Starts the effect from the beginning
+    pub fn play(&mut self) {
+        self.elapsed = Some(0.0);
+        self.played = true;
+    }
+    /// Returns true while the effect is playing
+    pub fn is_playing(&self) -> bool {
+        self.elapsed.is_some()
+    }
+}
+
+/// Event that plays the [`TextEffect`] of the entity
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PlayTextEffect(pub Entity);
+
+
+// #======================#
+// #=== TEXT RENDERING ===#
+
+/// Glyphs shown while decoding and glitching
+const TEXT_EFFECT_GLYPHS: &[char] = &['#', '%', '&', '@', '$', '*', '/', '\\', '<', '>', '?', '!', '0', '1', '=', '+'];
+/// How many times per second the random glyphs and glitch slices change
+const TEXT_EFFECT_RATE: f32 = 30.0;
+
+/// Cheap deterministic hash, so the effects look the same at any frame rate
+fn text_hash(a: u32, b: u32) -> u32 {
+    let mut x = a.wrapping_mul(0x27d4eb2d) ^ b.wrapping_mul(0x165667b1);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x85ebca6b);
+    x ^= x >> 13;
+    x
+}
+
+/// Returns the first section and the extra sections with their color override for the effect at the given time, or none if it has finished
+fn render_effect(effect: &TextEffect, elapsed: f32) -> Option<(String, Vec<(String, Option<Color>)>)> {
+    let length = effect.source.chars().count();
+    let step = (elapsed * TEXT_EFFECT_RATE) as u32;
+    match effect.kind {
+        TextEffectKind::Typewriter { .. } => {
+            let count = (elapsed * effect.speed) as usize;
+            (count < length).then(|| (effect.source.chars().take(count).collect(), Vec::new()))
+        },
+        TextEffectKind::Decode => {
+            let settled = (elapsed * effect.speed) as usize;
+            (settled < length).then(|| (effect.source.chars().enumerate().map(|(index, character)| {
+                if index < settled || character.is_whitespace() { character } else {
+                    TEXT_EFFECT_GLYPHS[text_hash(index as u32, step) as usize % TEXT_EFFECT_GLYPHS.len()]
+                }
+            }).collect(), Vec::new()))
+        },
+        TextEffectKind::Glitch => {
+            if elapsed >= effect.speed || length == 0 { return None; }
+
+            // Cut the text into three slices, then shift, recolor and corrupt the middle one
+            let hash = text_hash(step, length as u32);
+            let start = hash as usize % length;
+            let end = (start + 1 + (hash >> 8) as usize % length.div_ceil(2)).min(length);
+            let shift = " ".repeat((hash >> 16) as usize % 3);
+            let color = if hash & 1 == 0 { Color::BEVYPUNK_BLUE } else { Color::BEVYPUNK_YELLOW };
+            let characters: Vec<char> = effect.source.chars().collect();
+            let slice: String = characters[start..end].iter().enumerate().map(|(index, character)| {
+                let hash = text_hash(step, index as u32);
+                if hash % 3 == 0 && !character.is_whitespace() { TEXT_EFFECT_GLYPHS[hash as usize % TEXT_EFFECT_GLYPHS.len()] } else { *character }
+            }).collect();
+            Some((
+                characters[..start].iter().collect(),
+                vec![
+                    (format!("{shift}{slice}"), Some(color)),
+                    (characters[end..].iter().collect(), None),
+                ],
+            ))
+        },
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that starts the effects on spawn, on hover and on event
+fn text_effect_trigger_system(mut events: EventReader<PlayTextEffect>, mut query: Query<(Entity, &mut TextEffect, &Text, Option<&UiAnimator<Hover>>)>) {
+    let played: Vec<Entity> = events.read().map(|event| event.0).collect();
+    for (entity, mut effect, text, animator) in &mut query {
+        if played.contains(&entity) { effect.play(); }
+
+        match effect.trigger {
+            TextEffectTrigger::Spawn => {
+                if !effect.played && !text.sections[0].value.is_empty() { effect.play(); }
+            },
+            TextEffectTrigger::Hover => {
+                let hovered = animator.is_some_and(|animator| animator.animation_direction > 0.0);
+                if hovered && !effect.hovered { effect.play(); }
+                if effect.hovered != hovered { effect.hovered = hovered; }
+            },
+            TextEffectTrigger::Event => {},
+        }
+    }
+}
+
+/// System that renders the playing effects into the text
+fn text_effect_update_system(time: Res<Time>, audio: AudioBuses, mut query: Query<(&mut TextEffect, &mut Text)>) {
+    for (mut effect, mut text) in &mut query {
+        let Some(elapsed) = effect.elapsed else { continue };
+
+        // Adopt the text if something else changed it, for example the language
+        if elapsed == 0.0 || text.sections[0].value != effect.shown {
+            effect.source = text.sections[0].value.clone();
+        }
+
+        let next = elapsed + time.delta_seconds();
+        let rendered = render_effect(&effect, next);
+
+        // Play the typing sound when a new visible character appears
+        if let TextEffectKind::Typewriter { sound: Some(sound) } = &effect.kind {
+            let typed = |elapsed: f32| effect.source.chars().take((elapsed * effect.speed) as usize).filter(|c| !c.is_whitespace()).count();
+            if typed(next) > typed(elapsed) { audio.play(AudioBus::Ui, sound.clone()); }
+        }
+
+        // Write the frame, or restore the full text when finished
+        let style = text.sections[0].style.clone();
+        let (first, extra) = rendered.clone().unwrap_or_else(|| (effect.source.clone(), Vec::new()));
+        text.sections.truncate(1);
+        text.sections[0].value = first.clone();
+        for (value, color) in extra {
+            text.sections.push(TextSection { value, style: TextStyle { color: color.unwrap_or(style.color), ..style.clone() } });
+        }
+
+        effect.shown = first;
+        effect.elapsed = rendered.map(|_| next);
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct TextEffectPlugin;
+impl Plugin for TextEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<PlayTextEffect>()
+            .add_systems(Update, (text_effect_trigger_system, text_effect_update_system).chain().after(localized_text_system).before(UiSystems::Compute));
+    }
+}
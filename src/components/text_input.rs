@@ -0,0 +1,273 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a UI system is built. Clicking the input focuses it
+/// and the focused input receives the typed text. Changing any field after spawn updates the input.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct TextInput {
+    pub text: String,
+    /// Localization key of the text shown while the input is empty
+    pub placeholder: String,
+    /// Position of the caret in characters
+    pub cursor: usize,
+    pub focused: bool,
+}
+impl TextInput {
+    /// Replaces the text and moves the caret to its end
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.cursor = self.text.chars().count();
+    }
+    /// Byte index of the caret
+    fn cursor_byte(&self) -> usize {
+        self.text.char_indices().nth(self.cursor).map(|(index, _)| index).unwrap_or(self.text.len())
+    }
+}
+
+/// Event that is emmited when the user presses enter in a focused [`TextInput`]
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct TextInputSubmitEvent {
+    /// The text input entity
+    pub target: Entity,
+    pub text: String,
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Marker struct for the sandboxed UI
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct TextInputUi;
+
+/// Entities of the text input parts that change with the input
+#[derive(Component, Debug, Clone, PartialEq)]
+struct TextInputParts {
+    image: Entity,
+    text: Entity,
+    placeholder: Entity,
+}
+
+/// How often the caret blinks per second
+const TEXT_INPUT_CARET_BLINK: f32 = 2.0;
+
+/// System that builds the component UI
+fn build_component (mut commands: Commands, query: Query<(Entity, &TextInput), Added<TextInput>>, assets: Res<AssetServer>) {
+    for (entity, input) in &query {
+
+        let mut parts = None;
+
+        // This will create a private sandboxed UiTree within the entity just for the input
+        commands.entity(entity).insert(
+            UiTreeBundle::<TextInputUi>::from(UiTree::new2d("TextInput")),
+        ).with_children(|ui| {
+
+            // Spawn input image
+            let image = ui.spawn((
+                // Link this widget
+                UiLink::<TextInputUi>::path("Control/Image"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Give it a background image
+                UiImage2dBundle {
+                    texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+                    sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                    ..default()
+                },
+
+                // Make the background scalable
+                ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), ..default() }),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn input text
+            let text = ui.spawn((
+                // Link this widget
+                UiLink::<TextInputUi>::path("Control/Image/Text"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((3., 50.))).anchor(Anchor::CenterLeft).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section(input.text.clone(),
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_MEDIUM),
+                            font_size: 60.0,
+                            color: Color::BEVYPUNK_YELLOW.with_alpha(1.2),
+                        }),
+                    ..default()
+                },
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn input placeholder
+            let placeholder = ui.spawn((
+                // Link this widget
+                UiLink::<TextInputUi>::path("Control/Image/Placeholder"),
+
+                // Add layout
+                UiLayout::window().pos(Rl((3., 50.))).anchor(Anchor::CenterLeft).pack::<Base>(),
+
+                // Add text
+                UiText2dBundle {
+                    text: Text::from_section("",
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_REGULAR),
+                            font_size: 60.0,
+                            color: Color::BEVYPUNK_RED_DIM,
+                        }),
+                    ..default()
+                },
+
+                // Text is looked up from the string table
+                LocalizedText::new(&input.placeholder),
+
+                // Make it non-obsructable for hit checking (mouse detection)
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn input hover-zone
+            ui.spawn((
+                // Link this widget
+                UiLink::<TextInputUi>::path("Control"),
+
+                // Add layout
+                UiLayout::window_full().pack::<Base>(),
+
+                // Make this spacial & clickable entity
+                UiZoneBundle::default(),
+
+                // This is required to play the hover sound
+                UiAnimator::<Hover>::new(),
+
+                // This will play the widget sounds on hover
+                WidgetSoundZone::new(entity),
+
+                // This will change cursor icon on mouse hover
+                OnHoverSetCursor::new(CursorIcon::Pointer),
+
+                // If we click on this hover zone, it will emmit UiClick event from parent entity
+                UiClickEmitter::new(entity),
+            ));
+
+            parts = Some(TextInputParts { image, text, placeholder });
+        });
+
+        // Store the parts so we can update them later
+        if let Some(parts) = parts {
+            commands.entity(entity).insert(parts);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that focuses the clicked input and unfocuses the rest
+fn text_input_focus_system(mut events: EventReader<UiClickEvent>, mut query: Query<(Entity, &mut TextInput)>) {
+    for event in events.read() {
+        if !query.contains(event.target) { continue; }
+        for (entity, mut input) in &mut query {
+            let focused = entity == event.target;
+            if input.focused != focused { input.focused = focused; }
+        }
+    }
+}
+
+/// System that edits the focused input with the typed keys
+fn text_input_typing_system(mut events: EventReader<KeyboardInput>, mut query: Query<(Entity, &mut TextInput)>, mut submit: EventWriter<TextInputSubmitEvent>, mut sound: EventWriter<PlayWidgetSound>) {
+    let keys: Vec<Key> = events.read().filter(|event| event.state.is_pressed()).map(|event| event.logical_key.clone()).collect();
+    if keys.is_empty() { return; }
+
+    for (entity, mut input) in &mut query {
+        if !input.focused { continue; }
+        let length = input.text.chars().count();
+        input.cursor = input.cursor.min(length);
+
+        for key in &keys {
+            match key {
+                Key::Character(characters) => {
+                    let typed: String = characters.chars().filter(|c| !c.is_control()).collect();
+                    let index = input.cursor_byte();
+                    input.text.insert_str(index, &typed);
+                    input.cursor += typed.chars().count();
+                },
+                Key::Space => {
+                    let index = input.cursor_byte();
+                    input.text.insert(index, ' ');
+                    input.cursor += 1;
+                },
+                Key::Backspace if input.cursor > 0 => {
+                    input.cursor -= 1;
+                    let index = input.cursor_byte();
+                    input.text.remove(index);
+                },
+                Key::Delete if input.cursor < input.text.chars().count() => {
+                    let index = input.cursor_byte();
+                    input.text.remove(index);
+                },
+                Key::ArrowLeft => input.cursor = input.cursor.saturating_sub(1),
+                Key::ArrowRight => input.cursor = (input.cursor + 1).min(input.text.chars().count()),
+                Key::Home => input.cursor = 0,
+                Key::End => input.cursor = input.text.chars().count(),
+                Key::Enter => { submit.send(TextInputSubmitEvent { target: entity, text: input.text.clone() }); },
+
+                // Nothing left to erase
+                Key::Backspace | Key::Delete => { sound.send(PlayWidgetSound { target: entity, sound: WidgetSound::Denied }); },
+                _ => {},
+            }
+        }
+    }
+}
+
+/// System that renders the text, placeholder and the blinking caret
+fn text_input_update_system(time: Res<Time>, query: Query<(&TextInput, &TextInputParts)>, mut texts: Query<&mut Text, Without<LocalizedText>>, mut placeholders: Query<(&mut LocalizedText, &mut Visibility)>, mut images: Query<&mut Sprite>) {
+    let caret = (time.elapsed_seconds() * TEXT_INPUT_CARET_BLINK) as u32 % 2 == 0;
+    for (input, parts) in &query {
+        if let Ok(mut text) = texts.get_mut(parts.text) {
+            let mut value = input.text.clone();
+            if input.focused { value.insert(input.cursor_byte(), if caret { '|' } else { ' ' }); }
+            if text.sections[0].value != value { text.sections[0].value = value; }
+        }
+        if let Ok((mut placeholder, mut visibility)) = placeholders.get_mut(parts.placeholder) {
+            if placeholder.key != input.placeholder { placeholder.key = input.placeholder.clone(); }
+            let visible = if input.text.is_empty() && !input.focused { Visibility::Inherited } else { Visibility::Hidden };
+            if *visibility != visible { *visibility = visible; }
+        }
+        if let Ok(mut sprite) = images.get_mut(parts.image) {
+            let alpha = if input.focused { 0.3 } else { 0.15 };
+            if sprite.color.alpha() != alpha { sprite.color.set_alpha(alpha); }
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct TextInputPlugin;
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Add Lunex plugins for our sandboxed UI
+            .add_plugins(UiGenericPlugins::<TextInputUi>::new())
+
+            // Add general systems
+            .add_event::<TextInputSubmitEvent>()
+            .add_systems(Update, (text_input_focus_system.run_if(on_event::<UiClickEvent>()), text_input_typing_system, text_input_update_system).chain().before(UiSystems::Compute))
+            .add_systems(Update, build_component.before(UiSystems::Compute));
+    }
+}
@@ -0,0 +1,247 @@
+use bevy::text::TextLayoutInfo;
+use bevy::window::PrimaryWindow;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Add this component to any hover zone or widget to show a tooltip after hovering it for a while.
+/// Focused [`ScrollListRow`] entities show their tooltip too. The `text` field is a localization key.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct Tooltip {
+    pub text: String,
+}
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Tooltip { text: text.into() }
+    }
+}
+
+/// Add this component to a localized text node to display the active tooltip in it instead of the floating popup.
+/// The `placeholder` key is displayed while no tooltip is active.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct TooltipPanel {
+    pub placeholder: String,
+}
+
+/// How long the widget has to be hovered or focused before the tooltip shows up
+pub const TOOLTIP_DELAY: f32 = 0.6;
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Where the active tooltip came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TooltipSource {
+    /// The widget is under the cursor
+    Hover(Entity),
+    /// The widget is focused
+    Focus(Entity),
+}
+impl TooltipSource {
+    fn entity(&self) -> Entity {
+        match self {
+            TooltipSource::Hover(entity) | TooltipSource::Focus(entity) => *entity,
+        }
+    }
+}
+
+/// Resource tracking the active tooltip and the popup entities
+#[derive(Resource, Debug, Default)]
+struct TooltipState {
+    hovered: Option<Entity>,
+    source: Option<TooltipSource>,
+    timer: f32,
+    popup: Option<TooltipPopup>,
+}
+
+/// Entities of the floating popup
+#[derive(Debug, Clone, Copy)]
+struct TooltipPopup {
+    root: Entity,
+    fill: Entity,
+    frame: Entity,
+    text: Entity,
+}
+
+/// Padding between the popup frame and the text
+const TOOLTIP_PADDING: Vec2 = Vec2::new(18.0, 12.0);
+
+/// Spawns the floating popup as a child of the camera
+fn spawn_popup(commands: &mut Commands, camera: Entity, assets: &AssetServer) -> TooltipPopup {
+    let root = commands.spawn(SpatialBundle {
+        transform: Transform::from_xyz(0.0, 0.0, 150.0),
+        visibility: Visibility::Hidden,
+        ..default()
+    }).id();
+
+    // Spawn the dark fill so the text is readable on any background
+    let fill = commands.spawn(SpriteBundle {
+        sprite: Sprite { color: Color::BLACK.with_alpha(0.85), anchor: Anchor::TopLeft, ..default() },
+        ..default()
+    }).id();
+
+    // Spawn the themed frame
+    let frame = commands.spawn((
+        SpriteBundle {
+            texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+            sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.6), anchor: Anchor::TopLeft, ..default() },
+            transform: Transform::from_xyz(0.0, 0.0, 1.0),
+            ..default()
+        },
+        ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), max_corner_scale: 0.3, ..default() }),
+    )).id();
+
+    // Spawn the text
+    let text = commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("", TextStyle {
+                font: assets.load(PreLoader::FONT_MEDIUM),
+                font_size: 24.0,
+                color: Color::BEVYPUNK_YELLOW,
+            }),
+            text_anchor: Anchor::TopLeft,
+            transform: Transform::from_xyz(TOOLTIP_PADDING.x, -TOOLTIP_PADDING.y, 2.0),
+            ..default()
+        },
+        LocalizedText::default(),
+    )).id();
+
+    commands.entity(root).push_children(&[fill, frame, text]);
+    commands.entity(camera).add_child(root);
+    TooltipPopup { root, fill, frame, text }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// Returns the entity or its nearest ancestor that has a tooltip
+fn find_tooltip(entity: Entity, parents: &Query<&Parent>, tooltips: &Query<(), With<Tooltip>>) -> Option<Entity> {
+    std::iter::once(entity).chain(parents.iter_ancestors(entity)).find(|entity| tooltips.contains(*entity))
+}
+
+/// System that picks the active tooltip from hover and focus
+fn tooltip_source_system(
+    time: Res<Time>,
+    mut state: ResMut<TooltipState>,
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    parents: Query<&Parent>,
+    tooltips: Query<(), With<Tooltip>>,
+    focused: Query<(Entity, &ScrollListRow), With<Tooltip>>,
+) {
+    // Track the hovered tooltip
+    for event in out.read() {
+        if find_tooltip(event.target, &parents, &tooltips) == state.hovered { state.hovered = None; }
+    }
+    for event in over.read() {
+        if let Some(entity) = find_tooltip(event.target, &parents, &tooltips) { state.hovered = Some(entity); }
+    }
+    if state.hovered.is_some_and(|entity| !tooltips.contains(entity)) { state.hovered = None; }
+
+    // Hover takes priority over focus
+    let source = state.hovered.map(TooltipSource::Hover)
+        .or_else(|| focused.iter().find(|(_, row)| row.focused).map(|(entity, _)| TooltipSource::Focus(entity)));
+
+    if source.map(|source| source.entity()) != state.source.map(|source| source.entity()) {
+        state.timer = 0.0;
+    } else {
+        state.timer += time.delta_seconds();
+    }
+    state.source = source;
+}
+
+/// System that displays the active tooltip in the panel or in the floating popup
+#[allow(clippy::too_many_arguments)]
+fn tooltip_display_system(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut state: ResMut<TooltipState>,
+    tooltips: Query<&Tooltip>,
+    mut panels: Query<(&TooltipPanel, &mut LocalizedText)>,
+    mut texts: Query<&mut LocalizedText, Without<TooltipPanel>>,
+    layout: Query<&TextLayoutInfo>,
+    mut transforms: Query<&mut Transform, Without<Cursor2d>>,
+    mut sprites: Query<&mut Sprite>,
+    widgets: Query<(&GlobalTransform, &Dimension)>,
+    cursor: Query<&Transform, With<Cursor2d>>,
+    camera: Query<(Entity, &GlobalTransform), (With<MainUi>, With<Camera>)>,
+    window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let text = state.source.filter(|_| state.timer >= TOOLTIP_DELAY).and_then(|source| tooltips.get(source.entity()).ok()).map(|tooltip| tooltip.text.clone());
+
+    // Panels display the tooltip immediately and hide the popup
+    if !panels.is_empty() {
+        for (panel, mut localized) in &mut panels {
+            let key = state.source.and_then(|source| tooltips.get(source.entity()).ok()).map(|tooltip| tooltip.text.clone()).unwrap_or_else(|| panel.placeholder.clone());
+            if localized.key != key { localized.key = key; }
+        }
+        if let Some(popup) = state.popup { commands.entity(popup.root).insert(Visibility::Hidden); }
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = camera.get_single() else { return };
+    let popup = match state.popup {
+        Some(popup) => popup,
+        None => {
+            let popup = spawn_popup(&mut commands, camera, &assets);
+            state.popup = Some(popup);
+            return;
+        },
+    };
+
+    let Some(text) = text else {
+        commands.entity(popup.root).insert(Visibility::Hidden);
+        return;
+    };
+    if let Ok(mut localized) = texts.get_mut(popup.text) {
+        if localized.key != text { localized.key = text; }
+    }
+
+    // Size the background to the text
+    let size = layout.get(popup.text).map(|info| info.logical_size).unwrap_or_default() + TOOLTIP_PADDING * 2.0;
+    for part in [popup.fill, popup.frame] {
+        if let Ok(mut sprite) = sprites.get_mut(part) { sprite.custom_size = Some(size); }
+    }
+
+    // Place the popup next to the cursor or the focused widget, relative to the camera
+    let position = match state.source {
+        Some(TooltipSource::Focus(entity)) => widgets.get(entity).map(|(transform, dimension)| {
+            transform.translation().truncate() - camera_transform.translation().truncate() + Vec2::new(dimension.size.x + 12.0, 0.0)
+        }).ok(),
+        _ => cursor.get_single().map(|transform| transform.translation.truncate() + Vec2::new(24.0, -24.0)).ok(),
+    };
+    let Some(mut position) = position else { return };
+
+    // Flip to stay on screen
+    if let Ok(window) = window.get_single() {
+        let half = Vec2::new(window.width(), window.height()) / 2.0;
+        if position.x + size.x > half.x { position.x -= size.x + 48.0; }
+        if position.y - size.y < -half.y { position.y += size.y + 48.0; }
+        position = position.clamp(-half, half);
+    }
+
+    if let Ok(mut transform) = transforms.get_mut(popup.root) {
+        transform.translation = position.extend(transform.translation.z);
+    }
+    commands.entity(popup.root).insert(Visibility::Inherited);
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct TooltipPlugin;
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TooltipState>()
+
+            // Add general systems
+            .add_systems(Update, (tooltip_source_system, tooltip_display_system).chain().before(localized_text_system));
+    }
+}
@@ -0,0 +1,271 @@
+use bevy::ecs::system::SystemParam;
+use bevy::utils::HashMap;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Moments a widget plays a sound on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WidgetSound {
+    /// The pointer started hovering the widget
+    Hover,
+    /// The widget got keyboard or gamepad focus, or a text input was clicked
+    Focus,
+    Click,
+    /// The value of a slider, switch or spinner changed
+    Change,
+    /// The widget refused the interaction, for example a click on a disabled button
+    Denied,
+    /// Click on a return or cancel button
+    Back,
+}
+
+/// Sounds of a widget, none plays nothing
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WidgetSoundSet {
+    pub hover: Option<Handle<AudioSource>>,
+    pub focus: Option<Handle<AudioSource>>,
+    pub click: Option<Handle<AudioSource>>,
+    pub change: Option<Handle<AudioSource>>,
+    pub denied: Option<Handle<AudioSource>>,
+    pub back: Option<Handle<AudioSource>>,
+}
+impl WidgetSoundSet {
+    /// Returns the sound played on the moment
+    pub fn get(&self, sound: WidgetSound) -> Option<&Handle<AudioSource>> {
+        match sound {
+            WidgetSound::Hover => self.hover.as_ref(),
+            WidgetSound::Focus => self.focus.as_ref(),
+            WidgetSound::Click => self.click.as_ref(),
+            WidgetSound::Change => self.change.as_ref(),
+            WidgetSound::Denied => self.denied.as_ref(),
+            WidgetSound::Back => self.back.as_ref(),
+        }
+    }
+    /// Returns the mutable sound played on the moment
+    pub fn get_mut(&mut self, sound: WidgetSound) -> &mut Option<Handle<AudioSource>> {
+        match sound {
+            WidgetSound::Hover => &mut self.hover,
+            WidgetSound::Focus => &mut self.focus,
+            WidgetSound::Click => &mut self.click,
+            WidgetSound::Change => &mut self.change,
+            WidgetSound::Denied => &mut self.denied,
+            WidgetSound::Back => &mut self.back,
+        }
+    }
+}
+
+/// Resource with the default sounds of every widget type. Change it to restyle the sounds of the whole game.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WidgetSoundTheme {
+    pub button: WidgetSoundSet,
+    pub main_button: WidgetSoundSet,
+    pub slider: WidgetSoundSet,
+    pub spinner: WidgetSoundSet,
+    pub switch: WidgetSoundSet,
+    pub text_input: WidgetSoundSet,
+    /// Seconds before the same kind of sound can play again, so sweeping over a list does not stack the sounds
+    pub interval: f32,
+}
+impl FromWorld for WidgetSoundTheme {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        let sounds = WidgetSoundSet {
+            hover: Some(assets.load(PreLoader::SFX_UI)),
+            focus: Some(assets.load(PreLoader::SFX_UI_FOCUS)),
+            click: Some(assets.load(PreLoader::SFX_UI_CLICK)),
+            change: Some(assets.load(PreLoader::SFX_UI_CHANGE)),
+            denied: Some(assets.load(PreLoader::SFX_UI_DENIED)),
+            back: Some(assets.load(PreLoader::SFX_UI_BACK)),
+        };
+        WidgetSoundTheme {
+            button: sounds.clone(),
+            main_button: sounds.clone(),
+            slider: sounds.clone(),
+            spinner: sounds.clone(),
+            switch: sounds.clone(),
+            text_input: sounds,
+            interval: 0.06,
+        }
+    }
+}
+
+/// Add this component to a widget to override the sounds of the theme
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct WidgetSounds {
+    /// Sounds replacing the theme sounds, none keeps the theme sound
+    pub sounds: WidgetSoundSet,
+    /// Clicks play the back sound, for return and cancel buttons
+    pub back: bool,
+    /// The widget plays no sounds at all
+    pub silent: bool,
+}
+impl WidgetSounds {
+    pub fn new() -> Self {
+        WidgetSounds::default()
+    }
+    /// Creates the override of a return or cancel button
+    pub fn back() -> Self {
+        WidgetSounds { back: true, ..default() }
+    }
+    /// Creates the override of a widget without sounds
+    pub fn silent() -> Self {
+        WidgetSounds { silent: true, ..default() }
+    }
+    /// Replaces the theme sound of the moment
+    pub fn with(mut self, sound: WidgetSound, source: Handle<AudioSource>) -> Self {
+        *self.sounds.get_mut(sound) = Some(source);
+        self
+    }
+}
+
+/// Event that plays the sound of the widget. Entities that are not widgets use the button sounds.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PlayWidgetSound {
+    pub target: Entity,
+    pub sound: WidgetSound,
+}
+
+/// Add this component to the hover zone of a widget to play its hover and denied sounds
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WidgetSoundZone {
+    /// The widget entity
+    widget: Entity,
+    hovered: bool,
+}
+impl WidgetSoundZone {
+    pub fn new(widget: Entity) -> Self {
+        WidgetSoundZone { widget, hovered: false }
+    }
+}
+
+
+// #====================#
+// #=== SOUND LOOKUP ===#
+
+/// System parameter resolving the sounds of the widgets
+#[derive(SystemParam)]
+struct WidgetSoundLookup<'w, 's> {
+    theme: Res<'w, WidgetSoundTheme>,
+    widgets: Query<'w, 's, (
+        Option<&'static Button>,
+        Option<&'static MainButton>,
+        Option<&'static Slider>,
+        Option<&'static Spinner>,
+        Option<&'static Switch>,
+        Option<&'static TextInput>,
+        Option<&'static WidgetSounds>,
+    )>,
+}
+impl WidgetSoundLookup<'_, '_> {
+    /// Returns true if the widget is disabled
+    fn disabled(&self, entity: Entity) -> bool {
+        let Ok((button, main_button, slider, _, switch, _, _)) = self.widgets.get(entity) else { return false };
+        button.is_some_and(|button| button.disabled)
+            || main_button.is_some_and(|button| button.disabled)
+            || slider.is_some_and(|slider| slider.disabled)
+            || switch.is_some_and(|switch| switch.disabled)
+    }
+    /// Returns the sound that plays for the moment and its source
+    fn source(&self, entity: Entity, sound: WidgetSound) -> Option<(WidgetSound, Handle<AudioSource>)> {
+        let (button, main_button, slider, spinner, switch, text_input, sounds) = self.widgets.get(entity).unwrap_or_default();
+        if sounds.is_some_and(|sounds| sounds.silent) { return None; }
+        let sound = if sound == WidgetSound::Click && sounds.is_some_and(|sounds| sounds.back) { WidgetSound::Back } else { sound };
+
+        let theme = match (button, main_button, slider, spinner, switch, text_input) {
+            (_, Some(_), ..) => &self.theme.main_button,
+            (_, _, Some(_), ..) => &self.theme.slider,
+            (_, _, _, Some(_), ..) => &self.theme.spinner,
+            (_, _, _, _, Some(_), _) => &self.theme.switch,
+            (_, _, _, _, _, Some(_)) => &self.theme.text_input,
+            _ => &self.theme.button,
+        };
+        sounds.and_then(|sounds| sounds.sounds.get(sound)).or(theme.get(sound)).map(|source| (sound, source.clone()))
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that plays the hover sound and the denied sound when a disabled widget is clicked
+fn widget_sound_zone_system(mouse: Res<ButtonInput<MouseButton>>, lookup: WidgetSoundLookup, mut query: Query<(&UiAnimator<Hover>, &mut WidgetSoundZone)>, mut play: EventWriter<PlayWidgetSound>) {
+    for (animator, mut zone) in &mut query {
+        let hovered = animator.animation_direction > 0.0;
+        let disabled = lookup.disabled(zone.widget);
+        if hovered && !zone.hovered && !disabled {
+            play.send(PlayWidgetSound { target: zone.widget, sound: WidgetSound::Hover });
+        }
+        if hovered && disabled && mouse.just_pressed(MouseButton::Left) {
+            play.send(PlayWidgetSound { target: zone.widget, sound: WidgetSound::Denied });
+        }
+        if zone.hovered != hovered { zone.hovered = hovered; }
+    }
+}
+
+/// System that plays the click, focus and change sounds from the widget events
+fn widget_sound_event_system(
+    mut clicks: EventReader<UiClickEvent>,
+    mut sliders: EventReader<SliderChangeEvent>,
+    mut switches: EventReader<SwitchChangeEvent>,
+    mut spinners: EventReader<SpinnerChangeEvent>,
+    widgets: Query<(Has<Button>, Has<MainButton>, Has<TextInput>)>,
+    mut play: EventWriter<PlayWidgetSound>,
+) {
+    for event in clicks.read() {
+        // Switches and spinners play the change sound instead
+        let sound = match widgets.get(event.target) {
+            Ok((true, _, _) | (_, true, _)) => WidgetSound::Click,
+            Ok((_, _, true)) => WidgetSound::Focus,
+            _ => continue,
+        };
+        play.send(PlayWidgetSound { target: event.target, sound });
+    }
+    let changed = sliders.read().map(|event| event.target)
+        .chain(switches.read().map(|event| event.target))
+        .chain(spinners.read().map(|event| event.target));
+    play.send_batch(changed.map(|target| PlayWidgetSound { target, sound: WidgetSound::Change }));
+}
+
+/// System that plays the focus sound when the focus of a scroll list moves
+fn widget_sound_focus_system(mut focus: Local<HashMap<Entity, usize>>, lists: Query<(Entity, Ref<ScrollList>), Changed<ScrollList>>, rows: Query<(Entity, &ScrollListRow)>, mut play: EventWriter<PlayWidgetSound>) {
+    for (entity, list) in &lists {
+        let Some(index) = list.focus else {
+            focus.remove(&entity);
+            continue;
+        };
+
+        // The focus a list spawns with is silent
+        if focus.insert(entity, index) == Some(index) || list.is_added() { continue; }
+        let target = rows.iter().find(|(_, row)| row.list == entity && row.index == index).map(|(row, _)| row).unwrap_or(entity);
+        play.send(PlayWidgetSound { target, sound: WidgetSound::Focus });
+    }
+}
+
+/// System that plays the widget sounds on the UI bus, the same kind of sound plays at most once per interval
+fn widget_sound_play_system(time: Res<Time>, audio: AudioBuses, lookup: WidgetSoundLookup, mut events: EventReader<PlayWidgetSound>, mut played: Local<HashMap<WidgetSound, f32>>) {
+    let now = time.elapsed_seconds();
+    for event in events.read() {
+        let Some((sound, source)) = lookup.source(event.target, event.sound) else { continue };
+        if played.get(&sound).is_some_and(|last| now - last < lookup.theme.interval) { continue; }
+        played.insert(sound, now);
+        audio.play(AudioBus::Ui, source);
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct WidgetSoundPlugin;
+impl Plugin for WidgetSoundPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<WidgetSoundTheme>()
+            .add_event::<PlayWidgetSound>()
+            .add_systems(Update, (widget_sound_zone_system, widget_sound_event_system, widget_sound_focus_system, widget_sound_play_system).chain());
+    }
+}
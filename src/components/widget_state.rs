@@ -0,0 +1,175 @@
+use std::marker::PhantomData;
+use bevy::ecs::event::ManualEventReader;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Button-like widget whose flags drive the [`WidgetStateBlend`] of its entity
+pub trait StatefulWidget: Component {
+    fn disabled(&self) -> bool;
+    fn selected(&self) -> bool;
+    fn pressed(&self) -> bool;
+    fn set_pressed(&mut self, pressed: bool);
+}
+
+/// How much each state shows on the widget, from 0.0 to 1.0. Animated towards the widget flags.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct WidgetStateBlend {
+    pub selected: f32,
+    pub pressed: f32,
+    pub disabled: f32,
+}
+impl WidgetStateBlend {
+    /// Returns the blend fully settled in the state of the widget
+    pub fn target(widget: &impl StatefulWidget) -> Self {
+        let weight = |flag: bool| if flag { 1.0 } else { 0.0 };
+        WidgetStateBlend {
+            selected: weight(widget.selected()),
+            pressed: weight(widget.pressed()),
+            disabled: weight(widget.disabled()),
+        }
+    }
+    /// Blends the values of all states. Disabled is mixed in last so it wins, same as in [`WidgetState::resolve`].
+    pub fn blend<V>(&self, value: impl Fn(WidgetState) -> V, mix: impl Fn(V, V, f32) -> V) -> V {
+        let blended = value(WidgetState::Base);
+        let blended = mix(blended, value(WidgetState::Selected), self.selected);
+        let blended = mix(blended, value(WidgetState::Pressed), self.pressed);
+        mix(blended, value(WidgetState::Disabled), self.disabled)
+    }
+}
+
+/// Colors and offsets of a widget part for each state, blended by the [`WidgetStateBlend`] of the widget
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WidgetStyle {
+    /// The widget entity with the [`WidgetStateBlend`]
+    pub widget: Entity,
+    /// Base and hover color for the given state
+    pub colors: fn(WidgetState) -> (Color, Color),
+    /// Base and hover horizontal offset in % of the parent for the given state, if the part moves
+    pub offsets: Option<fn(WidgetState) -> (f32, f32)>,
+}
+impl WidgetStyle {
+    pub fn new(widget: Entity, colors: fn(WidgetState) -> (Color, Color)) -> Self {
+        WidgetStyle { widget, colors, offsets: None }
+    }
+    pub fn offsets(mut self, offsets: fn(WidgetState) -> (f32, f32)) -> Self {
+        self.offsets = Some(offsets);
+        self
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// How fast the widget blends into a new state, in full transitions per second
+const WIDGET_STATE_SPEED: f32 = 12.0;
+
+/// System that will set the pressed flag while the widget is held down by the mouse or by confirm on the focused row
+fn widget_press_system<T: StatefulWidget>(
+    mut events: ResMut<Events<UiClickEvent>>,
+    mut reader: Local<ManualEventReader<UiClickEvent>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    console: Res<Console>,
+    dialogs: Query<(), With<Dialog>>,
+    input: Res<ScrollInputFocus>,
+    mut query: Query<(Entity, &mut T, Option<&ScrollListRow>)>,
+) {
+    let clicked: Vec<Entity> = reader.read(&events).map(|event| event.target).collect();
+
+    // Dialogs handle their own confirm and the console eats the keys
+    let confirm_active = !console.open && dialogs.is_empty();
+    let confirm_held = confirm_active && (keyboard.pressed(KeyCode::Enter) || gamepad.get_pressed().any(|button| button.button_type == GamepadButtonType::South));
+    let confirm_just = confirm_active && (keyboard.just_pressed(KeyCode::Enter) || gamepad.get_just_pressed().any(|button| button.button_type == GamepadButtonType::South));
+
+    for (entity, mut widget, row) in &mut query {
+        let focused = row.is_some_and(|row| row.focused && input.get() == Some(row.list));
+        if widget.disabled() {
+            if widget.pressed() { widget.set_pressed(false); }
+        } else if clicked.contains(&entity) {
+            widget.set_pressed(true);
+        } else if focused && confirm_just {
+            // Confirm activates the focused row the same way as a click
+            widget.set_pressed(true);
+            events.send(UiClickEvent { target: entity });
+        } else if widget.pressed() && !mouse.pressed(MouseButton::Left) && !(focused && confirm_held) {
+            widget.set_pressed(false);
+        }
+    }
+}
+
+/// System that will animate the state blend towards the widget flags
+fn widget_blend_system<T: StatefulWidget>(time: Res<Time>, mut query: Query<(&T, &mut WidgetStateBlend)>) {
+    let step = WIDGET_STATE_SPEED * time.delta_seconds();
+    let approach = |value: f32, target: f32| if value < target { (value + step).min(target) } else { (value - step).max(target) };
+    for (widget, mut blend) in &mut query {
+        let target = WidgetStateBlend::target(widget);
+        if *blend == target { continue; }
+        *blend = WidgetStateBlend {
+            selected: approach(blend.selected, target.selected),
+            pressed: approach(blend.pressed, target.pressed),
+            disabled: approach(blend.disabled, target.disabled),
+        };
+    }
+}
+
+/// System that will write the blended colors and offsets into the widget parts
+fn widget_style_system(mut commands: Commands, blends: Query<Ref<WidgetStateBlend>>, parts: Query<(Entity, Ref<WidgetStyle>)>) {
+    let mix_color = |a: Color, b: Color, t: f32| Color::from(LinearRgba::from(a).mix(&LinearRgba::from(b), t));
+    let mix_offset = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    for (entity, style) in &parts {
+        let Ok(blend) = blends.get(style.widget) else { continue };
+        if !blend.is_changed() && !style.is_added() { continue; }
+
+        let base = blend.blend(|state| (style.colors)(state).0, mix_color);
+        let hover = blend.blend(|state| (style.colors)(state).1, mix_color);
+        commands.entity(entity).insert((UiColor::<Base>::new(base), UiColor::<Hover>::new(hover)));
+
+        if let Some(offsets) = style.offsets {
+            let base = blend.blend(|state| offsets(state).0, mix_offset);
+            let hover = blend.blend(|state| offsets(state).1, mix_offset);
+            commands.entity(entity).insert((
+                UiLayout::window_full().x(Rl(base)).pack::<Base>(),
+                UiLayout::window_full().x(Rl(hover)).pack::<Hover>(),
+            ));
+        }
+    }
+}
+
+
+// #========================#
+// #=== COMPONENT PLUGIN ===#
+
+/// Set of the systems that update the widget state blends
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WidgetStateSystems;
+
+/// Plugin adding the state logic for one widget type
+pub struct WidgetStatePlugin<T: StatefulWidget>(PhantomData<T>);
+impl<T: StatefulWidget> WidgetStatePlugin<T> {
+    pub fn new() -> Self {
+        WidgetStatePlugin(PhantomData)
+    }
+}
+impl<T: StatefulWidget> Default for WidgetStatePlugin<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: StatefulWidget> Plugin for WidgetStatePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (widget_press_system::<T>, widget_blend_system::<T>).chain().in_set(WidgetStateSystems).before(UiSystems::Compute));
+    }
+}
+
+/// Plugin adding the shared widget style logic
+pub struct WidgetStylePlugin;
+impl Plugin for WidgetStylePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, widget_style_system.after(WidgetStateSystems).before(UiSystems::Compute));
+    }
+}
@@ -0,0 +1,517 @@
+use std::collections::BTreeMap;
+use avian3d::prelude::*;
+use crate::*;
+
+
+// #========================#
+// #=== CONSOLE COMMANDS ===#
+
+/// Function running a console command. It gets the arguments without the command name
+/// and returns the printed output or an error message.
+pub type ConsoleRun = fn(&mut World, &[&str]) -> Result<String, String>;
+
+/// Function returning the autocompletion candidates for the arguments of a command
+pub type ConsoleComplete = fn(&World) -> Vec<String>;
+
+/// Command that can be run from the developer console
+#[derive(Debug, Clone)]
+pub struct ConsoleCommand {
+    /// Arguments of the command, shown in help
+    pub usage: &'static str,
+    /// What the command does, shown in help
+    pub help: &'static str,
+    pub run: ConsoleRun,
+    pub complete: Option<ConsoleComplete>,
+}
+impl ConsoleCommand {
+    pub fn new(usage: &'static str, help: &'static str, run: ConsoleRun) -> Self {
+        ConsoleCommand { usage, help, run, complete: None }
+    }
+    /// Adds autocompletion of the arguments
+    pub fn complete(mut self, complete: ConsoleComplete) -> Self {
+        self.complete = Some(complete);
+        self
+    }
+}
+
+/// Resource with all registered console commands
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ConsoleCommands {
+    commands: BTreeMap<String, ConsoleCommand>,
+}
+impl ConsoleCommands {
+    /// Registers the command, replacing any command with the same name
+    pub fn register(&mut self, name: impl Into<String>, command: ConsoleCommand) {
+        self.commands.insert(name.into(), command);
+    }
+    /// Returns the command with the given name
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.get(name)
+    }
+}
+
+/// Extension trait for registering console commands from plugins
+pub trait ConsoleAppExt {
+    /// Registers a command in the developer console
+    fn add_console_command(&mut self, name: impl Into<String>, command: ConsoleCommand) -> &mut Self;
+}
+impl ConsoleAppExt for App {
+    fn add_console_command(&mut self, name: impl Into<String>, command: ConsoleCommand) -> &mut Self {
+        self.world_mut().get_resource_or_insert_with(ConsoleCommands::default).register(name, command);
+        self
+    }
+}
+
+
+// #=====================#
+// #=== CONSOLE STATE ===#
+
+/// How many lines the console keeps
+const CONSOLE_LOG_LIMIT: usize = 200;
+/// How many lines of the log are displayed
+const CONSOLE_LOG_VISIBLE: usize = 12;
+
+/// Resource with the console log, history and the lines waiting to be run
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Console {
+    pub open: bool,
+    log: Vec<String>,
+    history: Vec<String>,
+    /// Position in the history while browsing it with arrow keys
+    browsing: Option<usize>,
+    pending: Vec<String>,
+    complete: bool,
+}
+impl Console {
+    /// Prints the line into the console log
+    pub fn print(&mut self, line: impl Into<String>) {
+        for line in line.into().lines() {
+            self.log.push(line.to_string());
+        }
+        if self.log.len() > CONSOLE_LOG_LIMIT {
+            let overflow = self.log.len() - CONSOLE_LOG_LIMIT;
+            self.log.drain(..overflow);
+        }
+    }
+}
+
+/// Condition that is true while the console is closed, add it to systems reacting to keyboard input
+pub fn console_closed(console: Res<Console>) -> bool {
+    !console.open
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Marker of the console overlay entity
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ConsoleOverlay {
+    log: Entity,
+    input: Entity,
+}
+
+/// Spawns the console overlay
+fn spawn_overlay(commands: &mut Commands, assets: &AssetServer) {
+    let mut overlay = None;
+    let mut entity = commands.spawn(SpatialBundle::default());
+    entity.with_children(|route| {
+
+        // Spawn the master ui tree
+        route.spawn((
+            UiTreeBundle::<MainUi>::from(UiTree::new2d("Console")),
+            SourceFromCamera,
+        )).with_children(|ui| {
+
+            // Spawn the root div
+            let root = UiLink::<MainUi>::path("Root");
+            ui.spawn((
+                root.clone(),
+                UiLayout::window_full().pack::<Base>(),
+
+                // Render the console on top of everything
+                UiDepthBias(100.0),
+            ));
+
+            // Spawn the panel blocking the input for the route beneath
+            let panel = root.add("Panel");
+            ui.spawn((
+                panel.clone(),
+                UiLayout::window().size(Rl((100.0, 45.0))).pack::<Base>(),
+                UiImage2dBundle {
+                    sprite: Sprite { color: Color::BLACK.with_alpha(0.85), ..default() },
+                    ..default()
+                },
+                PickableBundle::default(),
+            ));
+
+            // Spawn the log
+            let log = ui.spawn((
+                panel.add("Log"),
+                UiLayout::window().pos(Rl((2.0, 82.0))).anchor(Anchor::BottomLeft).pack::<Base>(),
+                UiText2dBundle {
+                    text: Text::from_section("",
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_MEDIUM),
+                            font_size: 60.0,
+                            color: Color::BEVYPUNK_RED,
+                        }),
+                    ..default()
+                },
+                UiTextSize::new().size(Rh(6.0)),
+                Pickable::IGNORE,
+            )).id();
+
+            // Spawn the input
+            let input = ui.spawn((
+                panel.add("Input"),
+                UiLayout::window().pos(Rl((1.0, 86.0))).size(Rl((98.0, 12.0))).pack::<Base>(),
+                TextInput { placeholder: "console-placeholder".into(), focused: true, ..default() },
+            )).id();
+
+            overlay = Some(ConsoleOverlay { log, input });
+        });
+    });
+    if let Some(overlay) = overlay {
+        entity.insert(overlay);
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that opens and closes the console with the backtick key
+fn console_toggle_system(mut commands: Commands, assets: Res<AssetServer>, keys: Res<ButtonInput<KeyCode>>, mut console: ResMut<Console>, overlays: Query<Entity, With<ConsoleOverlay>>) {
+    if !keys.just_pressed(KeyCode::Backquote) { return; }
+    console.open = !console.open;
+    console.browsing = None;
+    if console.open {
+        spawn_overlay(&mut commands, &assets);
+    } else {
+        for entity in &overlays { commands.entity(entity).despawn_recursive(); }
+    }
+}
+
+/// System that submits the typed lines and handles history and autocompletion keys
+fn console_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut console: ResMut<Console>,
+    mut submit: EventReader<TextInputSubmitEvent>,
+    overlays: Query<&ConsoleOverlay>,
+    mut inputs: Query<&mut TextInput>,
+) {
+    let Ok(overlay) = overlays.get_single() else { return };
+    let Ok(mut input) = inputs.get_mut(overlay.input) else { return };
+
+    // The backtick toggles the console, so it is never typed into it
+    if input.text.contains('`') {
+        let text = input.text.replace('`', "");
+        input.set_text(text);
+    }
+
+    // Submit the line
+    for event in submit.read() {
+        if event.target != overlay.input { continue; }
+        let line = event.text.trim().to_string();
+        input.set_text("");
+        console.browsing = None;
+        if line.is_empty() { continue; }
+        console.print(format!("> {line}"));
+        if console.history.last() != Some(&line) { console.history.push(line.clone()); }
+        console.pending.push(line);
+    }
+
+    // Browse the history
+    if keys.just_pressed(KeyCode::ArrowUp) && !console.history.is_empty() {
+        let index = console.browsing.map(|index| index.saturating_sub(1)).unwrap_or(console.history.len() - 1);
+        console.browsing = Some(index);
+        input.set_text(console.history[index].clone());
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        if let Some(index) = console.browsing {
+            if index + 1 < console.history.len() {
+                console.browsing = Some(index + 1);
+                input.set_text(console.history[index + 1].clone());
+            } else {
+                console.browsing = None;
+                input.set_text("");
+            }
+        }
+    }
+
+    // Autocompletion needs the world, so it is done by the exclusive system
+    if keys.just_pressed(KeyCode::Tab) { console.complete = true; }
+}
+
+/// System that runs the submitted lines and autocompletes the input
+fn console_execute_system(world: &mut World) {
+    let (pending, complete) = {
+        let mut console = world.resource_mut::<Console>();
+        (std::mem::take(&mut console.pending), std::mem::take(&mut console.complete))
+    };
+
+    // Run the submitted commands
+    for line in pending {
+        let arguments: Vec<&str> = line.split_whitespace().collect();
+        let command = world.resource::<ConsoleCommands>().get(arguments[0]).cloned();
+        let output = match command {
+            Some(command) => (command.run)(world, &arguments[1..]).unwrap_or_else(|error| format!("Error: {error}")),
+            None => format!("Unknown command \"{}\", type help to list all commands", arguments[0]),
+        };
+        if !output.is_empty() { world.resource_mut::<Console>().print(output); }
+    }
+
+    // Complete the last word of the input
+    if !complete { return; }
+    let Some(input) = world.query::<&ConsoleOverlay>().iter(world).next().map(|overlay| overlay.input) else { return };
+    let Some(text) = world.get::<TextInput>(input).map(|input| input.text.clone()) else { return };
+
+    let (head, word) = text.rsplit_once(' ').map(|(head, word)| (format!("{head} "), word.to_string())).unwrap_or((String::new(), text.clone()));
+    let candidates: Vec<String> = if head.trim().is_empty() {
+        world.resource::<ConsoleCommands>().commands.keys().cloned().collect()
+    } else {
+        let name = head.split_whitespace().next().unwrap_or_default();
+        world.resource::<ConsoleCommands>().get(name).and_then(|command| command.complete).map(|complete| complete(world)).unwrap_or_default()
+    };
+    let matches: Vec<String> = candidates.into_iter().filter(|candidate| candidate.starts_with(&word)).collect();
+
+    // Fill in the common prefix and list the options if there are more
+    let Some(first) = matches.first() else { return };
+    let mut prefix = first.clone();
+    for candidate in &matches[1..] {
+        let common = prefix.chars().zip(candidate.chars()).take_while(|(a, b)| a == b).count();
+        prefix = prefix.chars().take(common).collect();
+    }
+    if matches.len() > 1 { world.resource_mut::<Console>().print(matches.join("  ")); }
+    let suffix = if matches.len() == 1 { " " } else { "" };
+    if let Some(mut input) = world.get_mut::<TextInput>(input) {
+        input.set_text(format!("{head}{prefix}{suffix}"));
+    }
+}
+
+/// System that displays the end of the log
+fn console_log_system(console: Res<Console>, overlays: Query<&ConsoleOverlay>, mut texts: Query<&mut Text>) {
+    for overlay in &overlays {
+        let Ok(mut text) = texts.get_mut(overlay.log) else { continue };
+        let value = console.log[console.log.len().saturating_sub(CONSOLE_LOG_VISIBLE)..].join("\n");
+        if text.sections[0].value != value { text.sections[0].value = value; }
+    }
+}
+
+
+// #=========================#
+// #=== BUILT-IN COMMANDS ===#
+
+/// Parses the argument or returns a readable error
+fn parse<T: std::str::FromStr>(argument: &str) -> Result<T, String> {
+    argument.parse().map_err(|_| format!("Invalid value \"{argument}\""))
+}
+
+fn command_help(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let commands = world.resource::<ConsoleCommands>();
+    if let Some(name) = arguments.first() {
+        let command = commands.get(name).ok_or_else(|| format!("Unknown command \"{name}\""))?;
+        return Ok(format!("{name} {} - {}", command.usage, command.help));
+    }
+    Ok(commands.commands.iter().map(|(name, command)| format!("{name} {} - {}", command.usage, command.help)).collect::<Vec<_>>().join("\n"))
+}
+
+fn command_clear(world: &mut World, _arguments: &[&str]) -> Result<String, String> {
+    world.resource_mut::<Console>().log.clear();
+    Ok(String::new())
+}
+
+fn command_route(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let name = arguments.first().ok_or("Missing route")?;
+    let route = AppRoute::from_name(name).ok_or_else(|| format!("Unknown route \"{name}\""))?;
+
+    // Replace every route that is currently built, together with the dialogs open on top of it
+    let routes: Vec<Entity> = world.query_filtered::<Entity, Or<(
+        With<IntroRoute>, With<MainMenuRoute>, With<CharacterCreatorRoute>, With<GameRoute>,
+        With<SettingsRoute>, With<CreditsRoute>, With<AdditionalContentRoute>, With<LoadingRoute>,
+        With<Dialog>,
+    )>>().iter(world).collect();
+    for entity in routes { world.entity_mut(entity).despawn_recursive(); }
+    world.send_event(actions::HideCursor2d(false));
+    world.spawn(LoadingRoute::new(route));
+    Ok(format!("Loading {name}"))
+}
+
+fn command_route_complete(_world: &World) -> Vec<String> {
+    AppRoute::ALL.iter().map(|route| route.name().to_string()).collect()
+}
+
+fn command_teleport(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let [x, y, z] = arguments else { return Err("Expected x y z".into()) };
+    let position = Vec3::new(parse(x)?, parse(y)?, parse(z)?);
+    let mut found = false;
+    for (mut transform, velocity) in world.query_filtered::<(&mut Transform, Option<&mut LinearVelocity>), With<PlayerState>>().iter_mut(world) {
+        transform.translation = position;
+        if let Some(mut velocity) = velocity { velocity.0 = Vec3::ZERO; }
+        found = true;
+    }
+    if !found { return Err("There is no player".into()); }
+    Ok(format!("Teleported to {position}"))
+}
+
+/// Names of the player states
+const PLAYER_STATES: [&str; 6] = ["sprinting", "running", "base", "ads", "crouch", "prone"];
+
+/// Returns the player state with the given name
+fn player_state(name: &str) -> Option<PlayerState> {
+    match name {
+        "sprinting" => Some(PlayerState::Sprinting),
+        "running" => Some(PlayerState::Running),
+        "base" => Some(PlayerState::Base),
+        "ads" => Some(PlayerState::ADS),
+        "crouch" => Some(PlayerState::Crouch),
+        "prone" => Some(PlayerState::Prone),
+        _ => None,
+    }
+}
+
+fn command_state(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let name = arguments.first().ok_or("Missing state")?;
+    let Some(new_state) = player_state(name) else { return Err(format!("Unknown state \"{name}\"")) };
+    let mut found = false;
+    for mut state in world.query::<&mut PlayerState>().iter_mut(world) {
+        *state = new_state;
+        found = true;
+    }
+    if !found { return Err("There is no player".into()); }
+    Ok(format!("Player is now {name}"))
+}
+
+fn command_state_complete(_world: &World) -> Vec<String> {
+    PLAYER_STATES.iter().map(|name| name.to_string()).collect()
+}
+
+fn command_flicker(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let cameras: Vec<(Entity, bool)> = world.query_filtered::<(Entity, Has<VFXBloomFlicker>), (With<MainUi>, With<Camera>)>().iter(world).collect();
+    let Some((camera, enabled)) = cameras.first().copied() else { return Err("There is no camera".into()) };
+    let enable = match arguments.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !enabled,
+        Some(argument) => return Err(format!("Invalid value \"{argument}\", expected on or off")),
+    };
+    if enable { world.entity_mut(camera).insert(VFXBloomFlicker::default()); } else { world.entity_mut(camera).remove::<VFXBloomFlicker>(); }
+    Ok(format!("Bloom flicker {}", if enable { "on" } else { "off" }))
+}
+
+fn command_flicker_complete(_world: &World) -> Vec<String> {
+    vec!["on".into(), "off".into()]
+}
+
+fn command_shake(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let trauma: f32 = arguments.first().map(|argument| parse(argument)).transpose()?.unwrap_or(0.5);
+    world.send_event(AddTrauma(trauma));
+    Ok(format!("Added {trauma} trauma"))
+}
+
+fn command_glitch(world: &mut World, _arguments: &[&str]) -> Result<String, String> {
+    world.send_event(TriggerGlitch);
+    Ok(String::new())
+}
+
+fn command_gravity(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let gravity = match arguments {
+        [] => return Ok(format!("Gravity is {}", world.resource::<Gravity>().0)),
+        [y] => Vec3::new(0.0, parse(y)?, 0.0),
+        [x, y, z] => Vec3::new(parse(x)?, parse(y)?, parse(z)?),
+        _ => return Err("Expected y or x y z".into()),
+    };
+    world.insert_resource(Gravity(gravity));
+    Ok(format!("Gravity set to {gravity}"))
+}
+
+/// Marker of the props spawned from the console
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ConsoleProp;
+
+fn command_spawn(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let shape = arguments.first().copied().unwrap_or("cube");
+    let size: f32 = arguments.get(1).map(|size| parse(size)).transpose()?.unwrap_or(0.5);
+    let (mesh, collider) = match shape {
+        "cube" => (Mesh::from(Cuboid::new(size, size, size)), Collider::cuboid(size, size, size)),
+        "ball" => (Mesh::from(Sphere::new(size * 0.5)), Collider::sphere(size * 0.5)),
+        _ => return Err(format!("Unknown prop \"{shape}\", expected cube or ball")),
+    };
+
+    // Spawn the prop in front of the player, it is despawned together with the game route
+    let route = world.query_filtered::<Entity, With<GameRoute>>().iter(world).next().ok_or("Props can only be spawned in the game route")?;
+    let player = world.query_filtered::<&GlobalTransform, With<PlayerState>>().iter(world).next().map(|transform| transform.compute_transform()).unwrap_or_default();
+    let position = player.translation + player.forward() * 2.0 + Vec3::Y;
+
+    let mesh = world.resource_mut::<Assets<Mesh>>().add(mesh);
+    let material = world.resource_mut::<Assets<StandardMaterial>>().add(StandardMaterial { emissive: Color::BEVYPUNK_YELLOW.into(), ..default() });
+    let prop = world.spawn((
+        PbrBundle { mesh, material, transform: Transform::from_translation(position), ..default() },
+        RigidBody::Dynamic,
+        collider,
+        ConsoleProp,
+    )).id();
+    world.entity_mut(route).add_child(prop);
+    Ok(format!("Spawned {shape} at {position}"))
+}
+
+fn command_spawn_complete(_world: &World) -> Vec<String> {
+    vec!["cube".into(), "ball".into()]
+}
+
+fn command_tune(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    let target = *arguments.first().ok_or("Expected Resource.field")?;
+    let (resource, field) = target.split_once('.').unwrap_or((target, ""));
+    if let Some(value) = arguments.get(1) {
+        reflect_set(world, resource, field, value)?;
+    }
+    Ok(format!("{target} = {:?}", reflect_get(world, resource, field)?))
+}
+
+fn command_tune_complete(world: &World) -> Vec<String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut paths = Vec::new();
+    for registration in registry.iter() {
+        let Some(reflect) = registration.data::<ReflectResource>() else { continue };
+        let resource = registration.type_info().type_path_table().short_path();
+        paths.push(resource.to_string());
+
+        // Fields are listed only for the resources that exist
+        if let Some(value) = reflect.reflect(world) {
+            paths.extend(reflect_field_paths(value).into_iter().map(|field| format!("{resource}.{field}")));
+        }
+    }
+    paths
+}
+
+
+// #======================#
+// #=== CONSOLE PLUGIN ===#
+
+/// Plugin adding the developer console and its built-in commands
+pub struct ConsolePlugin;
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Console>()
+            .init_resource::<ConsoleCommands>()
+            .add_console_command("help", ConsoleCommand::new("[command]", "Lists all commands", command_help))
+            .add_console_command("clear", ConsoleCommand::new("", "Clears the console", command_clear))
+            .add_console_command("route", ConsoleCommand::new("<route>", "Loads the route", command_route).complete(command_route_complete))
+            .add_console_command("tp", ConsoleCommand::new("<x> <y> <z>", "Teleports the player", command_teleport))
+            .add_console_command("state", ConsoleCommand::new("<state>", "Sets the player state", command_state).complete(command_state_complete))
+            .add_console_command("flicker", ConsoleCommand::new("[on|off]", "Toggles the bloom flicker", command_flicker).complete(command_flicker_complete))
+            .add_console_command("shake", ConsoleCommand::new("[trauma]", "Shakes the camera", command_shake))
+            .add_console_command("glitch", ConsoleCommand::new("", "Plays the screen glitch", command_glitch))
+            .add_console_command("gravity", ConsoleCommand::new("[y | x y z]", "Prints or sets the physics gravity", command_gravity))
+            .add_console_command("spawn", ConsoleCommand::new("[cube|ball] [size]", "Spawns a test prop in front of the player", command_spawn).complete(command_spawn_complete))
+            .add_console_command("tune", ConsoleCommand::new("<Resource.field> [value]", "Reads or writes a field of a reflected resource", command_tune).complete(command_tune_complete))
+            .add_systems(Update, (
+                console_toggle_system,
+                console_input_system,
+                console_execute_system,
+                console_log_system,
+            ).chain().before(UiSystems::Compute));
+    }
+}
@@ -0,0 +1,253 @@
+use std::path::{Path, PathBuf};
+use bevy::utils::HashSet;
+#[cfg(not(target_family = "wasm"))]
+use bevy::asset::io::{file::FileAssetReader, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceId, ErasedAssetReader, PathStream, Reader};
+use crate::*;
+
+
+// #======================#
+// #=== CONTENT PACKS ===#
+
+/// Directory next to the game that is searched for content packs
+pub const CONTENT_DIR: &str = "content";
+/// Manifest file every content pack folder has to contain
+pub const CONTENT_MANIFEST: &str = "pack.toml";
+/// File listing the enabled content packs, one id per line
+const CONTENT_ENABLED: &str = "enabled.txt";
+
+/// Metadata of a content pack parsed from its manifest
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ContentManifest {
+    pub name: String,
+    pub version: String,
+    pub author: String,
+    pub description: String,
+    /// Path to the thumbnail image relative to the pack folder
+    pub thumbnail: Option<String>,
+}
+impl ContentManifest {
+    /// Parses the subset of TOML we use: `key = "value"` pairs and `#` comments
+    pub fn parse(source: &str) -> Self {
+        let mut manifest = ContentManifest::default();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') { continue; }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim() {
+                "name" => manifest.name = value,
+                "version" => manifest.version = value,
+                "author" => manifest.author = value,
+                "description" => manifest.description = value,
+                "thumbnail" => manifest.thumbnail = Some(value),
+                _ => {},
+            }
+        }
+        manifest
+    }
+}
+
+/// Content pack found in the content directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentPack {
+    /// Folder or archive name, used to persist the enabled state
+    pub id: String,
+    /// Path relative to the game directory
+    pub path: PathBuf,
+    pub manifest: Option<ContentManifest>,
+    /// Localization keys of the problems found while validating the pack
+    pub errors: Vec<&'static str>,
+}
+impl ContentPack {
+    /// Loads and validates the pack at the given path
+    fn load(base: &Path, path: PathBuf) -> Self {
+        let id = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let mut pack = ContentPack { id, path: Path::new(CONTENT_DIR).join(path.file_name().unwrap_or_default()), manifest: None, errors: Vec::new() };
+
+        // Archives are listed so the player knows why they are ignored, reading them is not supported yet
+        if path.is_file() {
+            pack.errors.push("content-error-archive");
+            return pack;
+        }
+        let Ok(source) = std::fs::read_to_string(path.join(CONTENT_MANIFEST)) else {
+            pack.errors.push("content-error-no-manifest");
+            return pack;
+        };
+
+        let manifest = ContentManifest::parse(&source);
+        if manifest.name.is_empty() || manifest.version.is_empty() || manifest.author.is_empty() {
+            pack.errors.push("content-error-missing-field");
+        }
+        if !manifest.version.is_empty() && !manifest.version.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+            pack.errors.push("content-error-version");
+        }
+        if manifest.thumbnail.as_ref().is_some_and(|thumbnail| !base.join(&pack.path).join(thumbnail).is_file()) {
+            pack.errors.push("content-error-thumbnail");
+        }
+        pack.manifest = Some(manifest);
+        pack
+    }
+    /// Returns true if the pack passed the validation
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+    /// Asset path of the thumbnail in the `content://` asset source
+    pub fn thumbnail(&self) -> Option<String> {
+        let thumbnail = self.manifest.as_ref()?.thumbnail.as_ref()?;
+        Some(format!("{CONTENT_DIR}://{}/{thumbnail}", self.id))
+    }
+    /// Folder with the asset overrides of this pack
+    fn assets(&self) -> PathBuf {
+        self.path.join("assets")
+    }
+}
+
+/// Resource with all discovered content packs and their enabled state
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ContentPacks {
+    pub packs: Vec<ContentPack>,
+    enabled: HashSet<String>,
+    /// Packs whose asset overrides are active, they only change on restart
+    active: HashSet<String>,
+}
+impl ContentPacks {
+    /// Discovers and validates all packs in the content directory
+    #[cfg(not(target_family = "wasm"))]
+    pub fn discover() -> Self {
+        let base = FileAssetReader::get_base_path();
+        let dir = base.join(CONTENT_DIR);
+
+        let mut packs: Vec<ContentPack> = std::fs::read_dir(&dir).into_iter().flatten().flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir() || path.extension().is_some_and(|extension| extension == "zip"))
+            .map(|path| ContentPack::load(&base, path))
+            .collect();
+        packs.sort_by(|a, b| a.id.cmp(&b.id));
+
+        // Only valid packs that still exist can stay enabled
+        let enabled: HashSet<String> = std::fs::read_to_string(dir.join(CONTENT_ENABLED)).unwrap_or_default().lines()
+            .map(|line| line.trim().to_string())
+            .filter(|id| packs.iter().any(|pack| &pack.id == id && pack.is_valid()))
+            .collect();
+
+        ContentPacks { packs, active: enabled.clone(), enabled }
+    }
+    /// Content packs are not supported on the web
+    #[cfg(target_family = "wasm")]
+    pub fn discover() -> Self {
+        ContentPacks::default()
+    }
+    /// Returns true if the pack is enabled
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.enabled.contains(id)
+    }
+    /// Enables or disables the pack and saves the change, invalid packs can't be enabled
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if enabled && !self.packs.iter().any(|pack| pack.id == id && pack.is_valid()) { return; }
+        let changed = if enabled { self.enabled.insert(id.to_string()) } else { self.enabled.remove(id) };
+        if changed { self.save(); }
+    }
+    /// Returns true if the enabled packs differ from the ones whose overrides are active
+    pub fn needs_restart(&self) -> bool {
+        self.enabled != self.active
+    }
+    /// Writes the enabled packs to the content directory
+    fn save(&self) {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let mut enabled: Vec<&String> = self.enabled.iter().collect();
+            enabled.sort();
+            let source: String = enabled.into_iter().map(|id| format!("{id}\n")).collect();
+            let dir = FileAssetReader::get_base_path().join(CONTENT_DIR);
+            if let Err(error) = std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(dir.join(CONTENT_ENABLED), source)) {
+                warn!("Failed to save enabled content packs: {error}");
+            }
+        }
+    }
+}
+
+
+// #=======================#
+// #=== ASSET OVERRIDES ===#
+
+/// Asset reader that looks into the enabled packs first and then into the default source.
+/// Directories are only listed from the default source.
+#[cfg(not(target_family = "wasm"))]
+struct ContentAssetReader {
+    overrides: Vec<FileAssetReader>,
+    fallback: Box<dyn ErasedAssetReader>,
+}
+#[cfg(not(target_family = "wasm"))]
+impl AssetReader for ContentAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        for reader in &self.overrides {
+            match reader.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        self.fallback.read(path).await
+    }
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        for reader in &self.overrides {
+            match reader.read_meta(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        self.fallback.read_meta(path).await
+    }
+    async fn read_directory<'a>(&'a self, path: &'a Path) -> Result<Box<PathStream>, AssetReaderError> {
+        self.fallback.read_directory(path).await
+    }
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        self.fallback.is_directory(path).await
+    }
+}
+
+
+/// System that reports the invalid packs, discovery runs before the log plugin is added so it can't log itself
+fn content_report_system(packs: Res<ContentPacks>) {
+    for pack in packs.packs.iter().filter(|pack| !pack.is_valid()) {
+        warn!("Content pack \"{}\" is invalid: {:?}", pack.id, pack.errors);
+    }
+}
+
+
+// #======================#
+// #=== CONTENT PLUGIN ===#
+
+/// Plugin discovering the content packs. It registers asset sources,
+/// so it has to be added before the `AssetPlugin` (part of the default plugins).
+pub struct ContentPlugin;
+impl Plugin for ContentPlugin {
+    fn build(&self, app: &mut App) {
+        let packs = ContentPacks::discover();
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            // Thumbnails and other pack files are loaded from the content:// source
+            app.register_asset_source(CONTENT_DIR, AssetSource::build().with_reader(|| Box::new(FileAssetReader::new(CONTENT_DIR))));
+
+            // Layer the enabled packs over the default source
+            let overrides: Vec<PathBuf> = packs.packs.iter().filter(|pack| packs.active.contains(&pack.id)).map(|pack| pack.assets()).collect();
+            if !overrides.is_empty() {
+                let mut builders = app.world_mut().get_resource_or_insert_with::<AssetSourceBuilders>(default);
+                if builders.get_mut(AssetSourceId::Default).is_none() {
+                    builders.insert(AssetSourceId::Default, AssetSource::build().with_reader(AssetSource::get_default_reader("assets".into())));
+                }
+                if let Some(builder) = builders.get_mut(AssetSourceId::Default) {
+                    if let Some(mut fallback) = builder.reader.take() {
+                        builder.reader = Some(Box::new(move || Box::new(ContentAssetReader {
+                            overrides: overrides.iter().map(FileAssetReader::new).collect(),
+                            fallback: fallback(),
+                        })));
+                    }
+                }
+            }
+        }
+
+        app.insert_resource(packs)
+            .add_systems(Startup, content_report_system);
+    }
+}
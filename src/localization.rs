@@ -0,0 +1,173 @@
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::utils::{HashMap, HashSet};
+use crate::*;
+
+
+// #=====================#
+// #=== LOCALE ASSETS ===#
+
+/// All languages the game ships with. The first one is the fallback language.
+pub const LANGUAGES: [&str; 2] = ["en", "cs"];
+
+/// Path to the string table of a language
+pub fn locale_path(language: &str) -> String {
+    format!("locales/{language}.ftl")
+}
+
+/// String table loaded from a `.ftl` file
+#[derive(Asset, TypePath, Debug, Default, Clone)]
+pub struct Locale {
+    pub strings: HashMap<String, String>,
+}
+impl Locale {
+    /// Parses the subset of Fluent we use: `key = value` messages, `#` comments
+    /// and indented lines continuing the previous message.
+    pub fn parse(source: &str) -> Self {
+        let mut strings: HashMap<String, String> = HashMap::new();
+        let mut last: Option<String> = None;
+        for line in source.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') { continue; }
+
+            // Continuation of the previous message
+            if line.starts_with(char::is_whitespace) {
+                if let Some(value) = last.as_ref().and_then(|key| strings.get_mut(key)) {
+                    if !value.is_empty() { value.push('\n'); }
+                    value.push_str(trimmed);
+                }
+                continue;
+            }
+
+            if let Some((key, value)) = trimmed.split_once('=') {
+                let key = key.trim().to_string();
+                strings.insert(key.clone(), value.trim().to_string());
+                last = Some(key);
+            }
+        }
+        Locale { strings }
+    }
+}
+
+/// Asset loader for `.ftl` string tables
+#[derive(Default)]
+struct LocaleLoader;
+impl AssetLoader for LocaleLoader {
+    type Asset = Locale;
+    type Settings = ();
+    type Error = std::io::Error;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), _load_context: &'a mut LoadContext<'_>) -> Result<Locale, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = std::str::from_utf8(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Locale::parse(source))
+    }
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+
+// #=====================#
+// #=== LOCALIZATION ===#
+
+/// Resource holding the active language and handles to all string tables
+#[derive(Resource, Debug, Clone)]
+pub struct Localization {
+    pub language: String,
+    locales: HashMap<String, Handle<Locale>>,
+}
+impl FromWorld for Localization {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Localization {
+            language: LANGUAGES[0].into(),
+            locales: LANGUAGES.iter().map(|language| (language.to_string(), assets.load(locale_path(language)))).collect(),
+        }
+    }
+}
+impl Localization {
+    /// Returns true if both the active and the fallback string tables are loaded
+    pub fn is_loaded(&self, locales: &Assets<Locale>) -> bool {
+        [self.language.as_str(), LANGUAGES[0]].iter().all(|language| self.locales.get(*language).is_some_and(|handle| locales.contains(handle)))
+    }
+    /// Looks up the key in the given language without any fallback
+    pub fn lookup(&self, language: &str, key: &str, locales: &Assets<Locale>) -> Option<String> {
+        locales.get(self.locales.get(language)?)?.strings.get(key).cloned()
+    }
+}
+
+/// Add this component to a text entity to have its first section driven by the localization key.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct LocalizedText {
+    pub key: String,
+}
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        LocalizedText { key: key.into() }
+    }
+}
+
+/// System that re-renders localized text on spawn, on key change and on language change
+pub(crate) fn localized_text_system(
+    mut events: EventReader<AssetEvent<Locale>>,
+    mut reported: Local<HashSet<(String, String)>>,
+    localization: Res<Localization>,
+    locales: Res<Assets<Locale>>,
+    mut query: Query<(Ref<LocalizedText>, &mut Text)>,
+) {
+    if !localization.is_loaded(&locales) { return; }
+    let reload = events.read().count() > 0 || localization.is_changed();
+
+    for (localized, mut text) in &mut query {
+        if !reload && !localized.is_changed() { continue; }
+
+        let value = match localization.lookup(&localization.language, &localized.key, &locales) {
+            _ if localized.key.is_empty() => String::new(),
+            Some(value) => value,
+            None => {
+                if reported.insert((localization.language.clone(), localized.key.clone())) {
+                    warn!("Missing localization key \"{}\" for language \"{}\"", localized.key, localization.language);
+                }
+                localization.lookup(LANGUAGES[0], &localized.key, &locales).unwrap_or_else(|| localized.key.clone())
+            }
+        };
+
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
+
+/// Event for changing the active language.
+#[derive(Event)]
+pub struct SetLanguage (pub String);
+fn set_language_event_system(mut events: EventReader<SetLanguage>, mut localization: ResMut<Localization>) {
+    for event in events.read() {
+        if !LANGUAGES.contains(&event.0.as_str()) {
+            warn!("Unknown language \"{}\"", event.0);
+            continue;
+        }
+        if localization.language != event.0 {
+            localization.language = event.0.clone();
+        }
+    }
+}
+
+
+// #===========================#
+// #=== LOCALIZATION PLUGIN ===#
+
+/// Plugin adding string tables and text localization
+pub struct LocalizationPlugin;
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<Locale>()
+            .init_asset_loader::<LocaleLoader>()
+            .init_resource::<Localization>()
+
+            .add_event::<SetLanguage>()
+            .add_systems(Update, set_language_event_system.run_if(on_event::<SetLanguage>()))
+            .add_systems(Update, localized_text_system.after(set_language_event_system).before(UiSystems::Compute));
+    }
+}
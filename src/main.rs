@@ -4,50 +4,173 @@ pub(crate) use bevy_kira_audio::prelude::*;
 #[cfg(not(target_family = "wasm"))]
 pub(crate) use vleue_kinetoscope::*;
 
+mod audio;
+use audio::*;
+
 mod boilerplate;
 use boilerplate::*;
 
+mod camera_motion;
+use camera_motion::*;
+
 mod components;
 use components::*;
 
+mod console;
+use console::*;
+
+mod content;
+use content::*;
+
+mod localization;
+use localization::*;
+
+mod music;
+use music::*;
+
+mod particles;
+use particles::*;
+
+mod post_process;
+use post_process::*;
+
 mod routes;
 use routes::*;
 
+mod tuning;
+use tuning::*;
+
+#[cfg(not(target_family = "wasm"))]
+mod verify;
+#[cfg(not(target_family = "wasm"))]
+use verify::*;
+
 
 fn main() {
+    // Parse the command line
+    if std::env::args().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{LAUNCH_USAGE}");
+        return;
+    }
+    let args = match LaunchArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}\n\n{LAUNCH_USAGE}");
+            std::process::exit(2);
+        },
+    };
+
+    // Check the assets instead of running the game
+    #[cfg(not(target_family = "wasm"))]
+    if args.verify_assets {
+        std::process::exit(verify_assets_report());
+    }
+
     // Our app
     let mut app = App::new();
 
     #[cfg(not(target_family = "wasm"))]
     app.add_plugins(bevy_embedded_assets::EmbeddedAssetPlugin { mode: bevy_embedded_assets::PluginMode::ReplaceDefault});
 
+    // Content packs and tuning register asset sources, so they have to come before the default plugins
+    app.add_plugins((ContentPlugin, TuningSourcePlugin));
+
     // Add plugins
-    let app = app
-        .add_plugins((default_plugins(), UiDefaultPlugins))
-        //.add_plugins(UiDebugPlugin::<MainUi>::new())
+    app
+        .add_plugins((default_plugins(&args), UiDefaultPlugins))
 
         // General setup
         .add_plugins(VFXPlugin)
+        .add_plugins(AudioBusPlugin)
+        .add_plugins(MusicPlugin)
+        .add_plugins(CameraMotionPlugin)
+        .add_plugins(PostProcessPlugin)
+        .add_plugins(ParticlePlugin)
+        .insert_resource(GameRng::new(args.seed))
         .add_systems(Startup, setup)
 
         // Add our plugins
+        .add_plugins(LocalizationPlugin)
+        .add_plugins(ConsolePlugin)
+        .add_plugins(TuningPlugin)
         .add_plugins(ComponentPlugin)
         .add_plugins(RoutePlugin);
 
-
-    #[cfg(not(target_family = "wasm"))]
-    if let Ok(intro) = AnimatedImageLoader::load_now_from_bytes(include_bytes!("../assets/images/intro/intro.gif"), "gif", app){
-        app.insert_resource(PreLoader { intro });
+    if args.debug_ui {
+        app.add_plugins((UiDebugPlugin::<MainUi>::new(), UiDebugPlugin::<Ui3d>::new()));
     }
 
-    app.run();
+    app.insert_resource(args).run();
 }
 
 
+// #=========================#
+// #=== COMMAND LINE ARGS ===#
+
+/// Help printed when the command line can't be parsed
+const LAUNCH_USAGE: &str = "\
+Usage: bevypunk [OPTIONS]
+
+Options:
+  --route <ROUTE>       Start in intro, main-menu, character-creator, game, settings, credits or additional-content
+  --windowed            Start in a window
+  --fullscreen          Start in fullscreen
+  --resolution <WxH>    Start with the given window resolution, for example 1920x1080
+  --no-audio            Mute all audio
+  --debug-ui            Draw the outlines of all ui nodes
+  --seed <SEED>         Seed the random generator, making the VFX reproducible
+  --verify-assets       Check that every referenced asset exists and decodes, then exit
+  -h, --help            Print this help
+
+The tuning file assets/tuning.tuning is reloaded on change only in builds with `--features dev`.";
+
+/// Options passed on the command line. They only apply to this run and are never saved.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct LaunchArgs {
+    pub route: AppRoute,
+    pub window_mode: Option<bevy::window::WindowMode>,
+    pub resolution: Option<Vec2>,
+    pub no_audio: bool,
+    pub debug_ui: bool,
+    pub seed: Option<u64>,
+    pub verify_assets: bool,
+}
+impl LaunchArgs {
+    /// Parses the arguments without the program name
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut launch = LaunchArgs::default();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or_else(|| format!("Missing value for {name}"));
+            match arg.as_str() {
+                "--route" => {
+                    let route = value("--route")?;
+                    launch.route = AppRoute::from_name(&route).ok_or_else(|| format!("Unknown route \"{route}\""))?;
+                },
+                "--windowed" => launch.window_mode = Some(bevy::window::WindowMode::Windowed),
+                "--fullscreen" => launch.window_mode = Some(bevy::window::WindowMode::BorderlessFullscreen),
+                "--resolution" => {
+                    let resolution = value("--resolution")?;
+                    let size = resolution.split_once('x').and_then(|(width, height)| Some(Vec2::new(width.parse().ok()?, height.parse().ok()?)));
+                    launch.resolution = Some(size.filter(|size| size.x > 0.0 && size.y > 0.0).ok_or_else(|| format!("Invalid resolution \"{resolution}\", expected WxH"))?);
+                },
+                "--no-audio" => launch.no_audio = true,
+                "--debug-ui" => launch.debug_ui = true,
+                "--seed" => {
+                    let seed = value("--seed")?;
+                    launch.seed = Some(seed.parse().map_err(|_| format!("Invalid seed \"{seed}\", expected a number"))?);
+                },
+                "--verify-assets" => launch.verify_assets = true,
+                arg => return Err(format!("Unknown argument \"{arg}\"")),
+            }
+        }
+        Ok(launch)
+    }
+}
+
 // #=====================#
 // #=== GENERIC SETUP ===#
 
-fn setup(mut commands: Commands, assets: Res<AssetServer>, mut atlas_layout: ResMut<Assets<TextureAtlasLayout>>, _audio: Res<Audio>){
+fn setup(mut commands: Commands, assets: Res<AssetServer>, args: Res<LaunchArgs>, mut atlas_layout: ResMut<Assets<TextureAtlasLayout>>){
     // Spawn 2D camera
     commands.spawn(camera()).with_children(|camera| {
 
@@ -83,19 +206,6 @@ fn setup(mut commands: Commands, assets: Res<AssetServer>, mut atlas_layout: Res
         ));
     });
 
-    #[cfg(not(target_family = "wasm"))]
-    {
-        // Spawn intro route
-        commands.spawn(IntroRoute);
-    }
-
-
-    #[cfg(target_family = "wasm")]
-    {
-        // Skip intro on wasm
-        commands.spawn(MainMenuRoute);
-
-        // Play audio
-        _audio.play(assets.load(PreLoader::MUSIC)).looped();
-    }
+    // Spawn the starting route, its playlist starts the music
+    commands.spawn(LoadingRoute::new(args.route));
 }
@@ -0,0 +1,202 @@
+use std::time::Duration;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Add this component to a route entity to declare its music. A single track loops,
+/// a playlist plays its tracks in order. Routes without a playlist keep the current music.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct MusicPlaylist {
+    /// An empty playlist fades the music out
+    pub tracks: Vec<Handle<AudioSource>>,
+    /// Volume relative to the music bus
+    pub volume: f64,
+    /// Start again after the last track
+    pub looped: bool,
+}
+impl MusicPlaylist {
+    /// Creates a looped playlist
+    pub fn new(tracks: Vec<Handle<AudioSource>>) -> Self {
+        MusicPlaylist { tracks, volume: 1.0, looped: true }
+    }
+    /// Creates a playlist looping a single track
+    pub fn track(track: Handle<AudioSource>) -> Self {
+        MusicPlaylist::new(vec![track])
+    }
+    /// Creates a playlist that fades the music out
+    pub fn silence() -> Self {
+        MusicPlaylist::new(Vec::new())
+    }
+    /// Sets the volume relative to the music bus
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = volume;
+        self
+    }
+    /// Plays the playlist only once
+    pub fn once(mut self) -> Self {
+        self.looped = false;
+        self
+    }
+}
+
+/// Add this component to any entity to duck the music while it exists, for example a pause menu.
+/// Open [`Dialog`]s duck the music too.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct MusicDuck;
+
+/// Event that plays the music right away, until another route declares its playlist
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PlayMusic(pub MusicPlaylist);
+
+/// Event that plays a short cue over the music, the music is ducked until it ends
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct MusicStinger(pub Handle<AudioSource>);
+
+
+// #======================#
+// #=== MUSIC DIRECTOR ===#
+
+/// Resource playing the music on the music bus. It crossfades between the playlists of routes,
+/// keeps the track playing when the next playlist contains it and ducks the music for dialogs and stingers.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MusicDirector {
+    /// Length of the crossfade between playlists in seconds
+    pub crossfade: f32,
+    /// Music volume multiplier while ducked
+    pub duck: f32,
+    /// Length of the fade into and out of the duck in seconds
+    pub duck_fade: f32,
+    playlist: MusicPlaylist,
+    /// Route entity the playlist was declared on
+    route: Option<Entity>,
+    /// Index of the playing track in the playlist
+    index: usize,
+    instance: Option<Handle<AudioInstance>>,
+    stinger: Option<Handle<AudioInstance>>,
+    /// Volume relative to the music bus the instance was last set to
+    volume: f64,
+}
+impl Default for MusicDirector {
+    fn default() -> Self {
+        MusicDirector {
+            crossfade: 2.0,
+            duck: 0.3,
+            duck_fade: 0.3,
+            playlist: MusicPlaylist::silence(),
+            route: None,
+            index: 0,
+            instance: None,
+            stinger: None,
+            volume: 0.0,
+        }
+    }
+}
+impl MusicDirector {
+    /// Returns the playing track
+    pub fn track(&self) -> Option<&Handle<AudioSource>> {
+        self.instance.as_ref().and(self.playlist.tracks.get(self.index))
+    }
+    /// Switches to the playlist, the current track keeps playing if the playlist contains it
+    fn switch(&mut self, playlist: MusicPlaylist, audio: &AudioBuses, instances: &mut Assets<AudioInstance>, volume: f64) {
+        if let Some(index) = self.track().and_then(|track| playlist.tracks.iter().position(|next| next == track)) {
+            self.playlist = playlist;
+            self.index = index;
+            return;
+        }
+
+        // Fade out the current track and fade in the new one
+        let tween = AudioTween::linear(Duration::from_secs_f32(self.crossfade));
+        if let Some(instance) = self.instance.take().and_then(|instance| instances.get_mut(&instance)) {
+            instance.stop(tween.clone());
+        }
+        self.playlist = playlist;
+        self.index = 0;
+        self.start(audio, volume, tween);
+    }
+    /// Starts the track at the index
+    fn start(&mut self, audio: &AudioBuses, volume: f64, tween: AudioTween) {
+        let Some(track) = self.playlist.tracks.get(self.index) else { return };
+        let mut command = audio.play_with_volume(AudioBus::Music, track.clone(), volume);
+        command.fade_in(tween);
+        if self.playlist.looped && self.playlist.tracks.len() == 1 { command.looped(); }
+        self.instance = Some(command.handle());
+        self.volume = volume;
+    }
+}
+
+/// Returns true if the sound has finished
+fn is_stopped(instance: &Handle<AudioInstance>, instances: &Assets<AudioInstance>) -> bool {
+    instances.get(instance).is_some_and(|instance| instance.state() == PlaybackState::Stopped)
+}
+
+/// System that switches the playlists, advances the tracks and ducks the music
+fn music_director_system(
+    mut director: ResMut<MusicDirector>,
+    audio: AudioBuses,
+    mut instances: ResMut<Assets<AudioInstance>>,
+    mut play: EventReader<PlayMusic>,
+    mut stingers: EventReader<MusicStinger>,
+    routes: Query<(Entity, &MusicPlaylist)>,
+    duckers: Query<(), Or<(With<Dialog>, With<MusicDuck>)>>,
+) {
+    let director = &mut *director;
+
+    // Play the stingers over the music
+    for stinger in stingers.read() {
+        director.stinger = Some(audio.play(AudioBus::Music, stinger.0.clone()).handle());
+    }
+    if director.stinger.as_ref().is_some_and(|stinger| is_stopped(stinger, &instances)) { director.stinger = None; }
+
+    // Relative volume of the music with the duck applied
+    let duck = if !duckers.is_empty() || director.stinger.is_some() { director.duck as f64 } else { 1.0 };
+    let relative = |playlist: &MusicPlaylist| playlist.volume * duck;
+
+    // Switch to the playlist of a new route or to the requested music
+    if let Some((route, playlist)) = routes.iter().last() {
+        if director.route != Some(route) {
+            director.route = Some(route);
+            let volume = relative(playlist);
+            director.switch(playlist.clone(), &audio, &mut instances, volume);
+        }
+    }
+    for PlayMusic(playlist) in play.read() {
+        let volume = relative(playlist);
+        director.switch(playlist.clone(), &audio, &mut instances, volume);
+    }
+
+    // Move to the next track when the current one ends
+    if director.instance.as_ref().is_some_and(|instance| is_stopped(instance, &instances)) {
+        director.instance = None;
+        director.index += 1;
+        if director.index >= director.playlist.tracks.len() && director.playlist.looped { director.index = 0; }
+        let volume = relative(&director.playlist);
+        director.start(&audio, volume, AudioTween::default());
+    }
+
+    // Duck the music, the bus volume is applied by the channel
+    let volume = relative(&director.playlist);
+    if director.volume != volume {
+        director.volume = volume;
+        if let Some(instance) = director.instance.as_ref().and_then(|instance| instances.get_mut(instance)) {
+            instance.set_volume(volume, AudioTween::linear(Duration::from_secs_f32(director.duck_fade)));
+        }
+    }
+}
+
+
+// #====================#
+// #=== MUSIC PLUGIN ===#
+
+/// Plugin adding the music director
+pub struct MusicPlugin;
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<MusicDirector>()
+            .add_event::<PlayMusic>()
+            .add_event::<MusicStinger>()
+            .add_systems(PostUpdate, music_director_system);
+    }
+}
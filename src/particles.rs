@@ -0,0 +1,429 @@
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::pbr::NotShadowCaster;
+use bevy::utils::HashMap;
+use rand::Rng;
+use crate::*;
+
+
+// #======================#
+// #=== PARTICLE ASSET ===#
+
+/// Value changing over the life of a particle, keys are pairs of normalized age and value
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticleCurve {
+    pub keys: Vec<(f32, f32)>,
+}
+impl ParticleCurve {
+    pub fn constant(value: f32) -> Self {
+        ParticleCurve { keys: vec![(0.0, value)] }
+    }
+    /// Returns the linearly interpolated value at the normalized age
+    pub fn sample(&self, t: f32) -> f32 {
+        sample_keys(&self.keys, t, |a, b, t| a.lerp(b, t)).unwrap_or(1.0)
+    }
+}
+
+/// Color changing over the life of a particle, keys are pairs of normalized age and color
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticleGradient {
+    pub keys: Vec<(f32, LinearRgba)>,
+}
+impl ParticleGradient {
+    pub fn constant(color: Color) -> Self {
+        ParticleGradient { keys: vec![(0.0, color.into())] }
+    }
+    /// Returns the linearly interpolated color at the normalized age
+    pub fn sample(&self, t: f32) -> LinearRgba {
+        sample_keys(&self.keys, t, |a, b, t| a.mix(&b, t)).unwrap_or(LinearRgba::WHITE)
+    }
+}
+
+/// Interpolates between the two keys around the time, the keys have to be sorted
+fn sample_keys<T: Copy>(keys: &[(f32, T)], t: f32, lerp: impl Fn(T, T, f32) -> T) -> Option<T> {
+    let index = keys.iter().position(|(time, _)| *time > t).unwrap_or(keys.len());
+    match (index.checked_sub(1).map(|index| keys[index]), keys.get(index)) {
+        (Some((start, a)), Some((end, b))) => Some(lerp(a, b, ((t - start) / (end - start)).clamp(0.0, 1.0))),
+        (Some((_, value)), None) | (None, Some((_, value))) => Some(*value),
+        (None, None) => None,
+    }
+}
+
+/// Particle effect loaded from a `.particles` file
+#[derive(Asset, TypePath, Debug, Clone, PartialEq)]
+pub struct ParticleEffect {
+    /// Particles spawned per second
+    pub rate: f32,
+    /// Particles spawned at once when the emitter starts
+    pub burst: u32,
+    /// Size of the particle pool, no more particles are alive at once
+    pub capacity: usize,
+    /// Minimum and maximum lifetime in seconds
+    pub lifetime: (f32, f32),
+    /// Half extents of the box particles spawn in. Emitters on ui nodes use the node size instead.
+    pub area: Vec3,
+    /// Direction of the initial velocity
+    pub direction: Vec3,
+    /// Random deviation from the direction in degrees
+    pub spread: f32,
+    /// Minimum and maximum initial speed
+    pub speed: (f32, f32),
+    /// Acceleration applied every second
+    pub gravity: Vec3,
+    /// Fraction of the velocity lost every second
+    pub drag: f32,
+    /// Velocity multiplier over the life
+    pub speed_curve: ParticleCurve,
+    /// Size over the life
+    pub size: ParticleCurve,
+    /// Color over the life
+    pub color: ParticleGradient,
+    /// How much the particles stretch along their velocity, for rain and sparks
+    pub stretch: f32,
+    /// Multiplier of the emissive color of 3D particles
+    pub emissive: f32,
+    /// Texture of 2D particles
+    pub image: Option<Handle<Image>>,
+}
+impl Default for ParticleEffect {
+    fn default() -> Self {
+        ParticleEffect {
+            rate: 10.0,
+            burst: 0,
+            capacity: 256,
+            lifetime: (1.0, 1.0),
+            area: Vec3::ZERO,
+            direction: Vec3::Y,
+            spread: 0.0,
+            speed: (1.0, 1.0),
+            gravity: Vec3::ZERO,
+            drag: 0.0,
+            speed_curve: ParticleCurve::constant(1.0),
+            size: ParticleCurve::constant(1.0),
+            color: ParticleGradient::constant(Color::WHITE),
+            stretch: 0.0,
+            emissive: 0.0,
+            image: None,
+        }
+    }
+}
+
+/// Splits comma separated curve keys into their time and value
+fn split_keys(value: &str) -> Option<Vec<(&str, &str)>> {
+    value.split(',').map(|pair| pair.trim().split_once(' ').map(|(time, value)| (time.trim(), value.trim()))).collect()
+}
+
+/// Asset loader for particle effects. The file is a list of `key = value` pairs, vectors and ranges are separated by spaces:
+/// `rate`, `burst`, `capacity`, `lifetime = min max`, `area = x y z`, `direction = x y z`, `spread`, `speed = min max`,
+/// `gravity = x y z`, `drag`, `stretch`, `emissive` and `image`. Curves are comma separated keys of age and value,
+/// `size = 0.0 4, 1.0 0` and `speed_curve`, the `color` gradient uses hex colors, `color = 0.0 #FCE208FF, 1.0 #FCE20800`.
+#[derive(Default)]
+struct ParticleEffectLoader;
+impl AssetLoader for ParticleEffectLoader {
+    type Asset = ParticleEffect;
+    type Settings = ();
+    type Error = std::io::Error;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), load_context: &'a mut LoadContext<'_>) -> Result<ParticleEffect, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = std::str::from_utf8(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        let invalid = |key: &str, value: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("Invalid {key} \"{value}\""));
+        let numbers = |key: &str, value: &str| value.split_whitespace().map(|number| number.parse::<f32>().map_err(|_| invalid(key, value))).collect::<Result<Vec<f32>, _>>();
+        let vector = |key: &str, value: &str| match numbers(key, value)?[..] {
+            [x, y, z] => Ok(Vec3::new(x, y, z)),
+            [x, y] => Ok(Vec3::new(x, y, 0.0)),
+            _ => Err(invalid(key, value)),
+        };
+        // Ranges are sampled when the particles spawn, so a reversed range is rejected here instead of panicking there
+        let range = |key: &str, value: &str| match numbers(key, value)?[..] {
+            [min, max] if min.is_finite() && max.is_finite() && min <= max => Ok((min, max)),
+            [single] if single.is_finite() => Ok((single, single)),
+            _ => Err(invalid(key, value)),
+        };
+
+        let mut effect = ParticleEffect::default();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let (key, value) = (key.trim(), value.trim().trim_matches('"'));
+            let number = || value.parse::<f32>().map_err(|_| invalid(key, value));
+
+            match key {
+                "rate" => effect.rate = number()?,
+                "burst" => effect.burst = value.parse().map_err(|_| invalid(key, value))?,
+                "capacity" => effect.capacity = value.parse().map_err(|_| invalid(key, value))?,
+                "lifetime" => effect.lifetime = range(key, value)?,
+                "area" => effect.area = vector(key, value)?,
+                "direction" => effect.direction = vector(key, value)?.normalize_or_zero(),
+                "spread" => effect.spread = number()?,
+                "speed" => effect.speed = range(key, value)?,
+                "gravity" => effect.gravity = vector(key, value)?,
+                "drag" => effect.drag = number()?,
+                "stretch" => effect.stretch = number()?,
+                "emissive" => effect.emissive = number()?,
+                "image" => effect.image = Some(load_context.load(value.to_string())),
+                "size" | "speed_curve" => {
+                    let curve = ParticleCurve { keys: split_keys(value).ok_or_else(|| invalid(key, value))?.into_iter().map(|(time, value)| Ok((time.parse().map_err(|_| invalid(key, time))?, value.parse().map_err(|_| invalid(key, value))?))).collect::<Result<_, std::io::Error>>()? };
+                    if key == "size" { effect.size = curve } else { effect.speed_curve = curve }
+                },
+                "color" => {
+                    effect.color = ParticleGradient { keys: split_keys(value).ok_or_else(|| invalid(key, value))?.into_iter().map(|(time, color)| Ok((time.parse().map_err(|_| invalid(key, time))?, Srgba::hex(color).map_err(|_| invalid(key, color))?.into()))).collect::<Result<_, std::io::Error>>()? };
+                },
+                _ => warn!("Unknown particle effect key \"{key}\""),
+            }
+        }
+        Ok(effect)
+    }
+    fn extensions(&self) -> &[&str] {
+        &["particles"]
+    }
+}
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Where the particles of an emitter are rendered
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleSpace {
+    /// Sprites, for the 2D `MainUi` camera
+    #[default]
+    Space2d,
+    /// Unlit meshes, for 3D cameras
+    Space3d,
+}
+
+/// Single simulated particle, dead particles are kept in the pool for reuse
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Particle {
+    alive: bool,
+    age: f32,
+    lifetime: f32,
+    position: Vec3,
+    velocity: Vec3,
+}
+
+/// Add this component to an entity to emit the particle effect around it. Particles are simulated on the CPU
+/// in the local space of the emitter and rendered by a pool of child entities that is reused, never despawned.
+/// When added to a ui node, the particles spawn over the whole node.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ParticleEmitter {
+    pub effect: Handle<ParticleEffect>,
+    pub space: ParticleSpace,
+    /// Stopped emitters spawn no new particles, the alive ones finish their life
+    pub playing: bool,
+    /// Particles owed from the previous frames
+    accumulator: f32,
+    /// If the burst was spawned
+    started: bool,
+    particles: Vec<Particle>,
+    /// Render entities of the particles, index matches the particle
+    slots: Vec<Entity>,
+}
+impl ParticleEmitter {
+    fn new(effect: Handle<ParticleEffect>, space: ParticleSpace) -> Self {
+        ParticleEmitter { effect, space, playing: true, accumulator: 0.0, started: false, particles: Vec::new(), slots: Vec::new() }
+    }
+    /// Creates an emitter of sprites
+    pub fn new2d(effect: Handle<ParticleEffect>) -> Self {
+        ParticleEmitter::new(effect, ParticleSpace::Space2d)
+    }
+    /// Creates an emitter of meshes
+    pub fn new3d(effect: Handle<ParticleEffect>) -> Self {
+        ParticleEmitter::new(effect, ParticleSpace::Space3d)
+    }
+    /// Returns how many particles are alive
+    pub fn alive(&self) -> usize {
+        self.particles.iter().filter(|particle| particle.alive).count()
+    }
+}
+
+/// Marker of the pooled render entities
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct ParticleSlot;
+
+/// Mesh shared by all 3D particles
+#[derive(Resource, Debug, Clone)]
+struct ParticleMesh(Handle<Mesh>);
+impl FromWorld for ParticleMesh {
+    fn from_world(world: &mut World) -> Self {
+        ParticleMesh(world.resource_mut::<Assets<Mesh>>().add(Sphere::new(0.5).mesh().ico(1).unwrap()))
+    }
+}
+
+/// Number of shared materials the color gradient of a 3D effect is baked into
+const PARTICLE_MATERIAL_STEPS: usize = 16;
+
+/// Materials shared by all 3D particles of an effect, one per step of its color gradient.
+/// Particles fade by switching between them, so no material is mutated while they play.
+#[derive(Resource, Debug, Default)]
+struct ParticleMaterials(HashMap<AssetId<ParticleEffect>, Vec<Handle<StandardMaterial>>>);
+impl ParticleMaterials {
+    /// Returns the materials of the effect, they are baked on the first use
+    fn get(&mut self, id: AssetId<ParticleEffect>, effect: &ParticleEffect, materials: &mut Assets<StandardMaterial>) -> &[Handle<StandardMaterial>] {
+        self.0.entry(id).or_insert_with(|| (0..PARTICLE_MATERIAL_STEPS).map(|step| {
+            let color = effect.color.sample(step as f32 / (PARTICLE_MATERIAL_STEPS - 1) as f32);
+            materials.add(StandardMaterial { base_color: color.into(), emissive: color * effect.emissive, unlit: true, alpha_mode: AlphaMode::Blend, ..default() })
+        }).collect())
+    }
+}
+
+
+// #========================#
+// #=== PARTICLE SYSTEMS ===#
+
+/// Returns a new particle spawned in the area
+fn spawn_particle(effect: &ParticleEffect, space: ParticleSpace, center: Vec3, half: Vec3, rng: &mut impl Rng) -> Particle {
+    let mut random = || Vec3::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+    let flat = |vector: Vec3| if space == ParticleSpace::Space2d { vector.with_z(0.0) } else { vector };
+
+    let position = center + half * random();
+    let deviation = flat(random()) * effect.spread.to_radians().sin();
+    let direction = (effect.direction + deviation).normalize_or_zero();
+    let (lifetime, speed) = (rng.gen_range(effect.lifetime.0..=effect.lifetime.1), rng.gen_range(effect.speed.0..=effect.speed.1));
+    Particle { alive: true, age: 0.0, lifetime, position, velocity: direction * speed }
+}
+
+/// System that spawns and simulates the particles
+fn particle_simulation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    effects: Res<Assets<ParticleEffect>>,
+    mesh: Res<ParticleMesh>,
+    mut query: Query<(Entity, &mut ParticleEmitter, Option<&Dimension>)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut emitter, dimension) in &mut query {
+        let Some(effect) = effects.get(&emitter.effect) else { continue };
+
+        // Ui nodes are positioned by their top left corner
+        let (center, half) = match dimension {
+            Some(dimension) => (Vec3::new(dimension.size.x, -dimension.size.y, 0.0) / 2.0, (dimension.size / 2.0).extend(0.0)),
+            None => (Vec3::ZERO, effect.area),
+        };
+
+        // Age and move the alive particles
+        for particle in emitter.particles.iter_mut().filter(|particle| particle.alive) {
+            particle.age += delta;
+            if particle.age >= particle.lifetime { particle.alive = false; continue; }
+
+            particle.velocity += effect.gravity * delta;
+            particle.velocity *= (1.0 - effect.drag * delta).max(0.0);
+            particle.position += particle.velocity * effect.speed_curve.sample(particle.age / particle.lifetime) * delta;
+        }
+
+        // Count the particles to spawn this frame
+        let mut count = 0;
+        if emitter.playing {
+            if !emitter.started { count += effect.burst as usize; }
+            emitter.accumulator += effect.rate * delta;
+            count += emitter.accumulator as usize;
+            emitter.accumulator = emitter.accumulator.fract();
+        }
+        emitter.started |= emitter.playing;
+
+        // Reuse dead particles first and grow the pool up to the capacity
+        for _ in 0..count {
+            let particle = spawn_particle(effect, emitter.space, center, half, &mut rng.0);
+            if let Some(dead) = emitter.particles.iter_mut().find(|particle| !particle.alive) {
+                *dead = particle;
+            } else if emitter.particles.len() < effect.capacity {
+                let slot = match emitter.space {
+                    ParticleSpace::Space2d => commands.spawn((
+                        SpriteBundle {
+                            texture: effect.image.clone().unwrap_or_default(),
+                            sprite: Sprite { custom_size: Some(Vec2::ONE), ..default() },
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        ParticleSlot,
+                    )).id(),
+                    ParticleSpace::Space3d => commands.spawn((
+                        PbrBundle {
+                            mesh: mesh.0.clone(),
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        NotShadowCaster,
+                        ParticleSlot,
+                    )).id(),
+                };
+                commands.entity(entity).add_child(slot);
+                emitter.particles.push(particle);
+                emitter.slots.push(slot);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+/// System that rebakes the shared materials of the changed effects
+fn particle_material_system(mut events: EventReader<AssetEvent<ParticleEffect>>, mut shared: ResMut<ParticleMaterials>) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            shared.0.remove(id);
+        }
+    }
+}
+
+/// System that writes the particles into their render entities
+fn particle_render_system(
+    effects: Res<Assets<ParticleEffect>>,
+    emitters: Query<&ParticleEmitter>,
+    mut slots: Query<(&mut Transform, &mut Visibility, Option<&mut Sprite>, Option<&mut Handle<StandardMaterial>>), With<ParticleSlot>>,
+    mut shared: ResMut<ParticleMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for emitter in &emitters {
+        let Some(effect) = effects.get(&emitter.effect) else { continue };
+        let steps: &[Handle<StandardMaterial>] = match emitter.space {
+            ParticleSpace::Space3d => shared.get(emitter.effect.id(), effect, &mut materials),
+            ParticleSpace::Space2d => &[],
+        };
+        for (particle, slot) in emitter.particles.iter().zip(&emitter.slots) {
+            let Ok((mut transform, mut visibility, sprite, material)) = slots.get_mut(*slot) else { continue };
+
+            if !particle.alive {
+                if *visibility != Visibility::Hidden { *visibility = Visibility::Hidden; }
+                continue;
+            }
+            *visibility = Visibility::Inherited;
+
+            // Stretch the particle along its velocity
+            let t = particle.age / particle.lifetime;
+            let size = effect.size.sample(t);
+            let speed = particle.velocity.length();
+            transform.translation = particle.position;
+            transform.rotation = if effect.stretch > 0.0 && speed > 0.0 { Quat::from_rotation_arc(Vec3::Y, particle.velocity / speed) } else { Quat::IDENTITY };
+            transform.scale = Vec3::new(size, size * (1.0 + effect.stretch * speed), size);
+
+            if let Some(mut sprite) = sprite {
+                sprite.color = effect.color.sample(t).into();
+            }
+            let step = ((t * (PARTICLE_MATERIAL_STEPS - 1) as f32).round() as usize).min(steps.len().saturating_sub(1));
+            if let (Some(mut material), Some(step)) = (material, steps.get(step)) {
+                if *material != *step { *material = step.clone(); }
+            }
+        }
+    }
+}
+
+
+// #=======================#
+// #=== PARTICLE PLUGIN ===#
+
+/// Plugin adding the particle effects
+pub struct ParticlePlugin;
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<ParticleEffect>()
+            .init_asset_loader::<ParticleEffectLoader>()
+            .init_resource::<ParticleMesh>()
+            .init_resource::<ParticleMaterials>()
+            .add_systems(Update, (particle_simulation_system, particle_material_system, particle_render_system).chain());
+    }
+}
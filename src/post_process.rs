@@ -0,0 +1,327 @@
+use bevy::core_pipeline::{core_2d::graph::{Core2d, Node2d}, core_3d::graph::{Core3d, Node3d}, fullscreen_vertex_shader::fullscreen_shader_vertex_state};
+use bevy::ecs::query::QueryItem;
+use bevy::render::{
+    extract_component::{ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin},
+    render_graph::{NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner},
+    render_resource::{binding_types::{sampler, texture_2d, uniform_buffer}, *},
+    renderer::{RenderContext, RenderDevice},
+    texture::BevyDefault,
+    view::ViewTarget,
+    RenderApp,
+};
+use crate::*;
+
+
+// #==========================#
+// #=== EXPOSED COMPONENTS ===#
+
+/// Add this component to a camera to darken every other line of the screen
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Scanlines {
+    pub intensity: f32,
+    /// Number of lines across the screen height
+    pub count: f32,
+}
+impl Default for Scanlines {
+    fn default() -> Self {
+        Scanlines { intensity: 0.15, count: 540.0 }
+    }
+}
+
+/// Add this component to a camera to bulge the screen like a curved CRT.
+/// Don't add it to the UI camera, the picking would no longer match the warped widgets.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct BarrelDistortion {
+    pub amount: f32,
+}
+impl Default for BarrelDistortion {
+    fn default() -> Self {
+        BarrelDistortion { amount: 0.03 }
+    }
+}
+
+/// Add this component to a camera to split the color channels towards the edges
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ChromaticAberration {
+    pub amount: f32,
+}
+impl Default for ChromaticAberration {
+    fn default() -> Self {
+        ChromaticAberration { amount: 0.004 }
+    }
+}
+
+/// Add this component to a camera to darken the corners
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Vignette {
+    pub intensity: f32,
+    /// Distance from the center where the darkening ends, 1 is the corner
+    pub radius: f32,
+}
+impl Default for Vignette {
+    fn default() -> Self {
+        Vignette { intensity: 0.4, radius: 1.1 }
+    }
+}
+
+/// Add this component to a camera to add animated noise
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct FilmGrain {
+    pub intensity: f32,
+}
+impl Default for FilmGrain {
+    fn default() -> Self {
+        FilmGrain { intensity: 0.03 }
+    }
+}
+
+/// Add this component to a camera to let it glitch with an RGB split when [`TriggerGlitch`] is sent.
+/// Like the [`BarrelDistortion`] it belongs on the game camera, not the UI camera.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct GlitchPulse {
+    pub strength: f32,
+    /// Seconds the glitch takes to fade out
+    pub duration: f32,
+    remaining: f32,
+}
+impl Default for GlitchPulse {
+    fn default() -> Self {
+        GlitchPulse { strength: 1.0, duration: 0.3, remaining: 0.0 }
+    }
+}
+impl GlitchPulse {
+    /// Starts the glitch
+    pub fn trigger(&mut self) {
+        self.remaining = self.duration;
+    }
+    /// Current strength of the fading glitch
+    pub fn value(&self) -> f32 {
+        if self.duration > 0.0 { self.strength * (self.remaining / self.duration).clamp(0.0, 1.0) } else { 0.0 }
+    }
+}
+
+/// Event triggering the glitch on every [`GlitchPulse`] camera
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct TriggerGlitch;
+
+/// How strong the screen effects are, changed from the settings
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ScreenEffectSettings {
+    /// Multiplier of all effects, zero turns them off
+    pub strength: f32,
+}
+impl Default for ScreenEffectSettings {
+    fn default() -> Self {
+        ScreenEffectSettings { strength: 1.0 }
+    }
+}
+
+/// Multipliers of the individual screen effects, editable at runtime from the tuning panel
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct ScreenEffectTuning {
+    pub scanlines: f32,
+    pub distortion: f32,
+    pub aberration: f32,
+    pub vignette: f32,
+    pub grain: f32,
+    pub glitch: f32,
+}
+impl Default for ScreenEffectTuning {
+    fn default() -> Self {
+        ScreenEffectTuning { scanlines: 1.0, distortion: 1.0, aberration: 1.0, vignette: 1.0, grain: 1.0, glitch: 1.0 }
+    }
+}
+
+
+// #=======================#
+// #=== EFFECT UNIFORMS ===#
+
+/// Parameters of all effects of a camera, sent to the shader. The layout matches `CrtUniform` in the shader.
+#[derive(Component, ExtractComponent, ShaderType, Debug, Default, Clone, Copy, PartialEq)]
+struct CrtUniform {
+    scanline_intensity: f32,
+    scanline_count: f32,
+    distortion: f32,
+    aberration: f32,
+    vignette_intensity: f32,
+    vignette_radius: f32,
+    grain_intensity: f32,
+    glitch: f32,
+    time: f32,
+    // WebGL2 requires uniforms to be aligned to 16 bytes
+    _padding_a: f32,
+    _padding_b: f32,
+    _padding_c: f32,
+}
+
+/// System that fades out the glitches and starts new ones
+fn glitch_pulse_system(time: Res<Time>, mut events: EventReader<TriggerGlitch>, mut query: Query<&mut GlitchPulse>) {
+    let trigger = events.read().count() > 0;
+    for mut glitch in &mut query {
+        if trigger { glitch.trigger(); }
+        if glitch.remaining > 0.0 { glitch.remaining = (glitch.remaining - time.delta_seconds()).max(0.0); }
+    }
+}
+
+/// System that collects the effect components of every camera into the uniform, or removes it if all effects are off
+fn crt_uniform_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<ScreenEffectSettings>,
+    tuning: Res<ScreenEffectTuning>,
+    query: Query<(Entity, Option<&Scanlines>, Option<&BarrelDistortion>, Option<&ChromaticAberration>, Option<&Vignette>, Option<&FilmGrain>, Option<&GlitchPulse>, Option<&CrtUniform>), With<Camera>>,
+) {
+    let strength = settings.strength;
+    for (entity, scanlines, distortion, aberration, vignette, grain, glitch, current) in &query {
+        let uniform = CrtUniform {
+            scanline_intensity: scanlines.map(|scanlines| scanlines.intensity * tuning.scanlines * strength).unwrap_or(0.0),
+            scanline_count: scanlines.map(|scanlines| scanlines.count).unwrap_or(0.0),
+            distortion: distortion.map(|distortion| distortion.amount * tuning.distortion * strength).unwrap_or(0.0),
+            aberration: aberration.map(|aberration| aberration.amount * tuning.aberration * strength).unwrap_or(0.0),
+            vignette_intensity: vignette.map(|vignette| vignette.intensity * tuning.vignette * strength).unwrap_or(0.0),
+            vignette_radius: vignette.map(|vignette| vignette.radius).unwrap_or(1.0),
+            grain_intensity: grain.map(|grain| grain.intensity * tuning.grain * strength).unwrap_or(0.0),
+            glitch: glitch.map(|glitch| glitch.value() * tuning.glitch * strength).unwrap_or(0.0),
+            time: time.elapsed_seconds_wrapped(),
+            ..default()
+        };
+
+        // Skip the pass entirely when there is nothing to draw
+        let visible = uniform.scanline_intensity != 0.0 || uniform.distortion != 0.0 || uniform.aberration != 0.0
+            || uniform.vignette_intensity != 0.0 || uniform.grain_intensity != 0.0 || uniform.glitch != 0.0;
+        if visible {
+            commands.entity(entity).insert(uniform);
+        } else if current.is_some() {
+            commands.entity(entity).remove::<CrtUniform>();
+        }
+    }
+}
+
+
+// #=========================#
+// #=== RENDER GRAPH NODE ===#
+
+/// Label of the post-process node in both the 2D and the 3D render graph
+#[derive(RenderLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct CrtLabel;
+
+/// Render graph node drawing the effects over the view target
+#[derive(Default)]
+struct CrtNode;
+impl ViewNode for CrtNode {
+    type ViewQuery = (&'static ViewTarget, &'static DynamicUniformIndex<CrtUniform>);
+
+    fn run(&self, _graph: &mut RenderGraphContext, render_context: &mut RenderContext, (view_target, uniform_index): QueryItem<Self::ViewQuery>, world: &World) -> Result<(), NodeRunError> {
+        let crt_pipeline = world.resource::<CrtPipeline>();
+        let pipeline_id = if view_target.is_hdr() { crt_pipeline.hdr } else { crt_pipeline.sdr };
+        let Some(pipeline) = world.resource::<PipelineCache>().get_render_pipeline(pipeline_id) else { return Ok(()) };
+        let Some(uniforms) = world.resource::<ComponentUniforms<CrtUniform>>().uniforms().binding() else { return Ok(()) };
+
+        // Read the rendered frame and write the processed one
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "crt_bind_group",
+            &crt_pipeline.layout,
+            &BindGroupEntries::sequential((post_process.source, &crt_pipeline.sampler, uniforms)),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("crt_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[uniform_index.index()]);
+        render_pass.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+/// Pipelines for HDR and SDR cameras sharing one bind group layout
+#[derive(Resource)]
+struct CrtPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    hdr: CachedRenderPipelineId,
+    sdr: CachedRenderPipelineId,
+}
+impl FromWorld for CrtPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let layout = render_device.create_bind_group_layout(
+            "crt_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    uniform_buffer::<CrtUniform>(true),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+        let shader = world.load_asset(PreLoader::SHADER_CRT);
+
+        let mut queue = |format: TextureFormat| world.resource_mut::<PipelineCache>().queue_render_pipeline(RenderPipelineDescriptor {
+            label: Some("crt_pipeline".into()),
+            layout: vec![layout.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: shader.clone(),
+                shader_defs: vec![],
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState { format, blend: None, write_mask: ColorWrites::ALL })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+        });
+        let hdr = queue(ViewTarget::TEXTURE_FORMAT_HDR);
+        let sdr = queue(TextureFormat::bevy_default());
+
+        CrtPipeline { layout, sampler, hdr, sdr }
+    }
+}
+
+
+// #=====================#
+// #=== EFFECT PLUGIN ===#
+
+/// Plugin adding the screen effects to the 2D and 3D render graphs
+pub struct PostProcessPlugin;
+impl Plugin for PostProcessPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_plugins((ExtractComponentPlugin::<CrtUniform>::default(), UniformComponentPlugin::<CrtUniform>::default()))
+            .init_resource::<ScreenEffectSettings>()
+            .register_tunables::<ScreenEffectTuning>(vec![
+                Tunable::new("scanlines", "tuning-effect-scanlines", 0.0, 2.0).step(0.05),
+                Tunable::new("distortion", "tuning-effect-distortion", 0.0, 2.0).step(0.05),
+                Tunable::new("aberration", "tuning-effect-aberration", 0.0, 2.0).step(0.05),
+                Tunable::new("vignette", "tuning-effect-vignette", 0.0, 2.0).step(0.05),
+                Tunable::new("grain", "tuning-effect-grain", 0.0, 2.0).step(0.05),
+                Tunable::new("glitch", "tuning-effect-glitch", 0.0, 2.0).step(0.05),
+            ])
+            .add_event::<TriggerGlitch>()
+            .add_systems(PostUpdate, (glitch_pulse_system, crt_uniform_system).chain());
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<CrtNode>>(Core3d, CrtLabel)
+            .add_render_graph_edges(Core3d, (Node3d::Tonemapping, CrtLabel, Node3d::EndMainPassPostProcessing))
+            .add_render_graph_node::<ViewNodeRunner<CrtNode>>(Core2d, CrtLabel)
+            .add_render_graph_edges(Core2d, (Node2d::Tonemapping, CrtLabel, Node2d::EndMainPassPostProcessing));
+    }
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+        render_app.init_resource::<CrtPipeline>();
+    }
+}
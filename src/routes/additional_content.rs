@@ -0,0 +1,309 @@
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a UI system is built
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct AdditionalContentRoute;
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// System that builds the route
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<AdditionalContentRoute>>, packs: Res<ContentPacks>) {
+    for route_entity in &query {
+        // #======================#
+        // #=== USER INTERFACE ===#
+
+        // Pick the row data, the enabled state is synced separately
+        let rows: Vec<(String, bool)> = packs.packs.iter().map(|pack| {
+            let subtitle = match &pack.manifest {
+                Some(manifest) if !manifest.name.is_empty() => format!("{} {}", manifest.name, manifest.version),
+                _ => pack.id.clone(),
+            };
+            (subtitle, pack.is_valid())
+        }).collect();
+
+        // Spawn the route
+        commands.entity(route_entity).insert(
+            SpatialBundle::default(),
+        ).with_children(|route| {
+
+            // Spawn the master ui tree
+            route.spawn((
+                UiTreeBundle::<MainUi>::from(UiTree::new2d("AdditionalContent")),
+                SourceFromCamera,
+            )).with_children(|ui| {
+
+                // Spawn the root div
+                let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
+                ui.spawn((
+                    root.clone(),                           // Here we add the link
+                    UiLayout::window_full().pack::<Base>(),         // This is where we define layout
+                ));
+
+                // Spawn the background
+                ui.spawn((
+                    root.add("Background"), // You can see here that we used existing "root" link to create chained link (same as "Root/Background")
+                    UiLayout::solid().size((2968.0, 1656.0)).scaling(Scaling::Fill).pack::<Base>(),
+                    UiImage2dBundle::from(assets.load(PreLoader::SETTINGS_BACKGROUND)),  // We use this bundle to add background image to our node
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn return button
+                ui.spawn((
+                    root.add("Return"),
+                    UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack::<Base>(),
+                    Button { text: "common-return-main-menu".into(), ..default() },
+                    WidgetSounds::back(),
+
+                    // If it detects UiClick event for this entity it will despawn route_entity and run a closure
+                    OnUiClickDespawn::new(route_entity),
+                    OnUiClickCommands::new(|commands| { commands.spawn(MainMenuRoute); })
+                ));
+
+                // Spawn title
+                ui.spawn((
+                    root.add("Title"),
+                    UiLayout::window().pos(Rl((50., 8.))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_SEMIBOLD),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new("content-title"),
+                    TextEffect::decode(40.0),
+                    UiTextSize::new().size(Rh(5.0)),
+                ));
+
+                // Spawn the pack list, the switches are built by the list
+                ui.spawn((
+                    root.add("List"),
+                    UiLayout::window().pos(Rl((6.0, 20.0))).size(Rl((40.0, 65.0))).pack::<Base>(),
+                    ScrollList::new(rows.len(), 14.0, move |row, index| {
+                        let (subtitle, valid) = rows[index].clone();
+                        row.insert((
+                            Switch { subtitle: Some(subtitle), disabled: !valid, ..default() },
+                            ContentPackRow { index },
+                        ));
+                    }),
+                ));
+
+                // Spawn the details panel
+                let details = root.add("Details");
+                ui.spawn((
+                    details.clone(),
+                    UiLayout::window().pos(Rl((52.0, 20.0))).size(Rl((42.0, 65.0))).pack::<Base>(),
+                    UiImage2dBundle {
+                        texture: assets.load(PreLoader::BUTTON_SYMETRIC_SLICED),
+                        sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                        ..default()
+                    },
+                    ImageScaleMode::Sliced(TextureSlicer { border: BorderRect::square(32.0), ..default() }),
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the thumbnail
+                ui.spawn((
+                    details.add("Boundary"),
+                    UiLayout::window().pos(Rl((5.0, 5.0))).size(Rl((90.0, 50.0))).pack::<Base>(),
+                ));
+                ui.spawn((
+                    details.add("Boundary/Thumbnail"),
+                    UiLayout::solid().size((1920.0, 1080.0)).pack::<Base>(),
+                    UiImage2dBundle::default(),
+                    Pickable::IGNORE,
+                    ContentDetail::Thumbnail,
+                ));
+
+                // Spawn the texts of the details
+                for (name, detail, y, font, color, size) in [
+                    ("Name", ContentDetail::Name, 60.0, PreLoader::FONT_SEMIBOLD, Color::BEVYPUNK_YELLOW.with_alpha(1.2), 7.0),
+                    ("Info", ContentDetail::Info, 69.0, PreLoader::FONT_MEDIUM, Color::BEVYPUNK_RED, 4.5),
+                    ("Description", ContentDetail::Description, 77.0, PreLoader::FONT_REGULAR, Color::BEVYPUNK_RED, 4.5),
+                    ("Error", ContentDetail::Error, 87.0, PreLoader::FONT_MEDIUM, Color::BEVYPUNK_YELLOW, 4.5),
+                ] {
+                    let mut text = ui.spawn((
+                        details.add(name),
+                        UiLayout::window().pos(Rl((5.0, y))).anchor(Anchor::TopLeft).pack::<Base>(),
+                        UiText2dBundle {
+                            text: Text::from_section("",
+                                TextStyle {
+                                    font: assets.load(font),
+                                    font_size: 60.0,
+                                    color,
+                                }),
+                            ..default()
+                        },
+                        UiTextSize::new().size(Rh(size)),
+                        Pickable::IGNORE,
+                    ));
+
+                    // The error is a localization key, the rest comes from the manifest
+                    if detail == ContentDetail::Error { text.insert(LocalizedText::default()); }
+                    text.insert(detail);
+                }
+
+                // Spawn the note under the list
+                ui.spawn((
+                    root.add("Note"),
+                    UiLayout::window().pos(Rl((50., 90.))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::default(),
+                    UiTextSize::new().size(Rh(3.0)),
+                    ContentDetail::Note,
+                ));
+            });
+        });
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// Component on the list rows linking them to the content pack
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ContentPackRow {
+    index: usize,
+}
+
+/// Nodes of the details panel
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+enum ContentDetail {
+    Thumbnail,
+    Name,
+    Info,
+    Description,
+    Error,
+    /// Empty list or restart hint under the list
+    Note,
+}
+
+/// Localization key of the pack status displayed on its switch
+fn status_key(pack: &ContentPack, packs: &ContentPacks) -> &'static str {
+    if !pack.is_valid() { "content-pack-invalid" } else if packs.is_enabled(&pack.id) { "content-pack-enabled" } else { "content-pack-disabled" }
+}
+
+/// System that enables or disables the packs when their switch is flipped
+fn content_switch_system(mut events: EventReader<SwitchChangeEvent>, rows: Query<&ContentPackRow>, mut packs: ResMut<ContentPacks>) {
+    for event in events.read() {
+        if let Ok(row) = rows.get(event.target) {
+            if let Some(id) = packs.packs.get(row.index).map(|pack| pack.id.clone()) {
+                packs.set_enabled(&id, event.on);
+            }
+        }
+    }
+}
+
+/// System that syncs the switches with the enabled packs
+fn content_row_sync_system(packs: Res<ContentPacks>, mut rows: Query<(Ref<ContentPackRow>, &mut Switch)>) {
+    for (row, mut switch) in &mut rows {
+        if !packs.is_changed() && !row.is_changed() { continue; }
+        let Some(pack) = packs.packs.get(row.index) else { continue };
+        let on = packs.is_enabled(&pack.id);
+        let text = status_key(pack, &packs);
+        if switch.on != on { switch.on = on; }
+        if switch.text != text { switch.text = text.into(); }
+    }
+}
+
+/// System that shows the hovered or focused pack in the details panel
+#[allow(clippy::too_many_arguments)]
+fn content_details_system(
+    mut selected: Local<Option<usize>>,
+    mut focused: Local<Option<usize>>,
+    mut thumbnail_handle: Local<Option<(String, Handle<Image>)>>,
+    assets: Res<AssetServer>,
+    packs: Res<ContentPacks>,
+    mut over: EventReader<Pointer<Over>>,
+    parents: Query<&Parent>,
+    rows: Query<(&ContentPackRow, &ScrollListRow)>,
+    mut texts: Query<(&ContentDetail, &mut Text), Without<LocalizedText>>,
+    mut localized: Query<(&ContentDetail, &mut LocalizedText)>,
+    mut thumbnails: Query<(&ContentDetail, &mut Handle<Image>, &mut Visibility)>,
+) {
+    // Select the last hovered or newly focused row
+    for event in over.read() {
+        if let Some(row) = std::iter::once(event.target).chain(parents.iter_ancestors(event.target)).find_map(|entity| rows.get(entity).ok()) {
+            *selected = Some(row.0.index);
+        }
+    }
+    let focus = rows.iter().find(|(_, row)| row.focused).map(|(row, _)| row.index);
+    if *focused != focus {
+        *focused = focus;
+        if focus.is_some() { *selected = focus; }
+    }
+    if selected.is_none() && !packs.packs.is_empty() { *selected = Some(0); }
+
+    let pack = selected.and_then(|index| packs.packs.get(index));
+    let manifest = pack.and_then(|pack| pack.manifest.clone()).unwrap_or_default();
+
+    // Update the plain texts
+    for (detail, mut text) in &mut texts {
+        let value = match detail {
+            ContentDetail::Name => pack.map(|pack| if manifest.name.is_empty() { pack.id.clone() } else { manifest.name.clone() }).unwrap_or_default(),
+            ContentDetail::Info if !manifest.version.is_empty() => format!("v{} | {}", manifest.version, manifest.author),
+            ContentDetail::Description => manifest.description.clone(),
+            _ => String::new(),
+        };
+        if text.sections[0].value != value { text.sections[0].value = value; }
+    }
+
+    // Update the localized texts
+    for (detail, mut text) in &mut localized {
+        let key = match detail {
+            ContentDetail::Error => pack.and_then(|pack| pack.errors.first().copied()).unwrap_or_default(),
+            ContentDetail::Note if packs.packs.is_empty() => "content-empty",
+            ContentDetail::Note if packs.needs_restart() => "content-restart",
+            _ => "",
+        };
+        if text.key != key { text.key = key.into(); }
+    }
+
+    // Update the thumbnail, it is only loaded when the selection changes
+    let thumbnail = pack.filter(|pack| pack.is_valid()).and_then(|pack| pack.thumbnail());
+    match &thumbnail {
+        Some(path) if thumbnail_handle.as_ref().map(|(loaded, _)| loaded) != Some(path) => *thumbnail_handle = Some((path.clone(), assets.load(path.clone()))),
+        None => *thumbnail_handle = None,
+        _ => {},
+    }
+    for (detail, mut image, mut visibility) in &mut thumbnails {
+        if *detail != ContentDetail::Thumbnail { continue; }
+        let (handle, visible) = match thumbnail_handle.as_ref() {
+            Some((_, handle)) => (handle.clone(), Visibility::Inherited),
+            None => (Handle::default(), Visibility::Hidden),
+        };
+        if *image != handle { *image = handle; }
+        if *visibility != visible { *visibility = visible; }
+    }
+}
+
+
+// #====================#
+// #=== ROUTE PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct AdditionalContentRoutePlugin;
+impl Plugin for AdditionalContentRoutePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreUpdate, build_route.before(UiSystems::Compute))
+            .add_systems(Update, (content_switch_system.run_if(on_event::<SwitchChangeEvent>()), content_row_sync_system, content_details_system).chain().before(UiSystems::Compute));
+    }
+}
@@ -1,3 +1,5 @@
+use std::f32::consts::{PI, TAU};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::render::render_resource::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
 
 use crate::*;
@@ -15,8 +17,11 @@ pub struct CharacterCreatorRoute;
 // #=== SANDBOXED USER INTEFACE ===#
 
 /// System that builds the route
-fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<CharacterCreatorRoute>>, asset_server: Res<AssetServer>) {
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<CharacterCreatorRoute>>, asset_server: Res<AssetServer>, mut data: ResMut<CharacterData>) {
     for route_entity in &query {
+        // The creator always opens with the default character, same as the spinners
+        *data = CharacterData::default();
+
         // #======================#
         // #=== USER INTERFACE ===#
 
@@ -40,13 +45,16 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
         image.resize(size);
         let render_image = asset_server.add(image);
 
+        // Keep the menu music playing
+        commands.entity(route_entity).insert(MusicPlaylist::track(assets.load(PreLoader::MUSIC)));
+
         // Spawn the route
         commands.entity(route_entity).insert(
             SpatialBundle::default(),
         ).with_children(|route| {
 
-            // Spawn 3D camera
-            route.spawn(Camera3dBundle {
+            // Spawn 3D camera, the showcase controls move it between the shots
+            route.spawn((Camera3dBundle {
                 camera: Camera {
                     order: -1,
                     target: render_image.clone().into(),
@@ -55,13 +63,13 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                     ..default()
                 },
                 ..default()
-            });
+            }, ShowcaseCamera::default()));
 
             // Spawn 3D model in the scene
             route.spawn((
                 SceneBundle {
-                    scene: asset_server.load("models/Female1.glb#Scene0"),
-                    transform: Transform::from_xyz(-0.3, -1.5, -1.0),
+                    scene: asset_server.load(format!("{}#Scene0", PreLoader::character_model("Female", 1))),
+                    transform: Transform::from_translation(SHOWCASE_BASE),
                     ..default()
                 },
                 Showcase,
@@ -104,18 +112,31 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                     root.add("Background/Camera"),
                     UiLayout::solid().size((1920.0, 1080.0)).scaling(Scaling::Fill).pack::<Base>(),
                     UiImage2dBundle::from(render_image),
-                    Pickable::IGNORE,
+
+                    // Dragging the view rotates the model and scrolling zooms
+                    ShowcaseView::default(),
+                    OnHoverSetCursor::new(CursorIcon::Grab),
                 ));
                 
                 // Spawn return button
                 ui.spawn((
                     root.add("Return"),
                     UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack::<Base>(),
-                    Button { text: "<- Main menu".into() },
+                    Button { text: "common-return-main-menu".into(), ..default() },
+                    WidgetSounds::back(),
 
-                    // If it detects UiClick event for this entity it will despawn route_entity and run a closure
-                    OnUiClickDespawn::new(route_entity),
-                    OnUiClickCommands::new(|commands| { commands.spawn((MainMenuRoute, SourceFromCamera)); })
+                    // Returning asks for confirmation if the character was changed
+                    CreatorReturnButton { route: route_entity },
+                ));
+
+                // Spawn confirm button
+                ui.spawn((
+                    root.add("Confirm"),
+                    UiLayout::window().pos(Rl((82.0, 4.0))).size(Rl((16.0, 8.0))).pack::<Base>(),
+                    Button { text: "creator-confirm".into(), ..default() },
+
+                    // Confirming plays the stinger and starts the game
+                    CreatorConfirmButton { route: route_entity },
                 ));
     
                 // Spawn panel boundary
@@ -138,7 +159,7 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                     panel.add("Text"),
                     UiLayout::window().pos(Rl((53., 8.))).anchor(Anchor::TopCenter).pack::<Base>(),
                     UiText2dBundle {
-                        text: Text::from_section("Character creator",
+                        text: Text::from_section("",
                             TextStyle {
                                 font: assets.load(PreLoader::FONT_SEMIBOLD),
                                 font_size: 60.0,
@@ -146,37 +167,24 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                             }),
                         ..default()
                     },
+                    LocalizedText::new("creator-title"),
+                    TextEffect::decode(40.0),
                     UiTextSize::new().size(Rh(5.0)),
                 ));
     
-                // Spawn button boundary
-                let list = panel.add("List");
+                // Spawn the spinner list, the spinners are built by the list
                 ui.spawn((
-                    list.clone(),
+                    panel.add("List"),
                     UiLayout::window().pos(Rl((53.0, 15.0))).anchor(Anchor::TopCenter).size(Rl((60.0, 65.0))).pack::<Base>(),
+                    ScrollList::new(CREATOR_SPINNERS.len(), 14.0, |row, index| {
+                        let (spinner, name, options) = &CREATOR_SPINNERS[index];
+                        let options = options.iter().map(|(id, label)| SpinnerOption::new(*id, *label)).collect();
+                        row.insert((
+                            Spinner::new(*name, options),
+                            spinner.clone(),
+                        ));
+                    }).gap(5.0),
                 ));
-    
-                // Spawn buttons
-                let gap = 5.0;
-                let size = 14.0;
-                let mut offset = 0.0;
-                for array in [
-                    ( "Gender", vec!["Female", "Male"]),
-                    ( "Body", vec!["Body 1", "Body 2", "Body 3"]),
-                    ( "Color", vec!["Red", "Blue"]),
-                    ( "Hair", vec!["Short", "Bun", "Long", "Ponytail"]),
-                    ( "Beard", vec!["None"]),
-                ] {
-                    let options: Vec<String> = array.1.iter().map(|&s| s.to_string()).collect();
-    
-                    ui.spawn((
-                        list.add(array.0),
-                        UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack::<Base>(),
-                        Spinner { name: array.0.into(), index: 0, options },
-                    ));
-    
-                    offset += gap + size;
-                }
             });
         });
     }
@@ -186,41 +194,308 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
 // #=====================#
 // #=== INTERACTIVITY ===#
 
+/// Spinners of the character creator with their option ids and labels
+const CREATOR_SPINNERS: [(CreatorSpinner, &str, &[(&str, &str)]); 5] = [
+    (CreatorSpinner::Gender, "creator-gender", &[("Female", "creator-gender-female"), ("Male", "creator-gender-male")]),
+    (CreatorSpinner::Body, "creator-body", &[("1", "creator-body-1"), ("2", "creator-body-2"), ("3", "creator-body-3")]),
+    (CreatorSpinner::Color, "creator-color", &[("red", "creator-color-red"), ("blue", "creator-color-blue")]),
+    (CreatorSpinner::Hair, "creator-hair", &[("short", "creator-hair-short"), ("bun", "creator-hair-bun"), ("long", "creator-hair-long"), ("ponytail", "creator-hair-ponytail")]),
+    (CreatorSpinner::Beard, "creator-beard", &[("none", "creator-beard-none")]),
+];
+
+/// Good practice is to use custom component for spinners, so we can easily know which spinner was changed
+#[derive(Component, Clone, PartialEq)]
+enum CreatorSpinner {
+    Gender,
+    Body,
+    Color,
+    Hair,
+    Beard,
+}
+
 #[derive(Resource)]
 struct CharacterData {
     gender: String,
     body: u8,
+    /// If the character was changed since the creator was opened
+    dirty: bool,
+}
+impl Default for CharacterData {
+    fn default() -> Self {
+        CharacterData { gender: "Female".into(), body: 1, dirty: false }
+    }
 }
 
-#[derive(Component)]
-struct Showcase;
-fn showcase_rotate_system(mut query: Query<&mut Transform, With<Showcase>>, mut local: Local<f32>, time: Res<Time>) {
-    *local += time.delta_seconds();
-    for mut transform in &mut query {
-        *transform = transform.with_rotation(Quat::from_euler(EulerRot::XYZ, 0.0, (20.0 * local.sin()).to_radians(), 0.0));
+/// Return button of the character creator
+#[derive(Component, Clone, PartialEq)]
+struct CreatorReturnButton {
+    route: Entity,
+}
+
+/// Returns to the main menu, asks to discard the character changes first
+fn creator_return_system(mut commands: Commands, mut events: EventReader<UiClickEvent>, query: Query<&CreatorReturnButton>, data: Res<CharacterData>) {
+    for event in events.read() {
+        if let Ok(button) = query.get(event.target) {
+            let route = button.route;
+            let return_to_menu = move |commands: &mut Commands| {
+                commands.entity(route).despawn_recursive();
+                commands.spawn(LoadingRoute::new(AppRoute::MainMenu));
+                commands.add(|world: &mut World| { *world.resource_mut::<CharacterData>() = CharacterData::default(); });
+            };
+
+            if data.dirty {
+                commands.spawn((
+                    Dialog::confirm("dialog-discard-title", "dialog-discard-message"),
+                    OnDialogResult::new(DialogChoice::Confirm, return_to_menu),
+                ));
+            } else {
+                return_to_menu(&mut commands);
+            }
+        }
+    }
+}
+
+/// Confirm button of the character creator
+#[derive(Component, Clone, PartialEq)]
+struct CreatorConfirmButton {
+    route: Entity,
+}
+
+/// Confirms the character and starts the game
+fn creator_confirm_system(mut commands: Commands, mut events: EventReader<UiClickEvent>, query: Query<&CreatorConfirmButton>, mut data: ResMut<CharacterData>, assets: Res<AssetServer>, mut stinger: EventWriter<MusicStinger>) {
+    for event in events.read() {
+        if let Ok(button) = query.get(event.target) {
+            stinger.send(MusicStinger(assets.load(PreLoader::STINGER_CONFIRM)));
+            commands.entity(button.route).despawn_recursive();
+            commands.spawn(LoadingRoute::new(AppRoute::Game));
+            data.dirty = false;
+        }
     }
 }
-fn showcase_swap_system(mut events: EventReader<UiChangeEvent>, asset_server: Res<AssetServer>, mut data: ResMut<CharacterData>, mut query: Query<&mut Handle<Scene>, With<Showcase>>) {
+
+#[derive(Component)]
+struct Showcase;
+fn showcase_swap_system(mut events: EventReader<SpinnerChangeEvent>, asset_server: Res<AssetServer>, mut data: ResMut<CharacterData>, spinners: Query<&CreatorSpinner>, mut query: Query<&mut Handle<Scene>, With<Showcase>>) {
     for event in events.read() {
-        info!("{}", event.value);
-
-        match event.value.as_str() {
-            "Male" => data.gender = "Male".into(),
-            "Female" => data.gender = "Female".into(),
-            "Body 1" => data.body = 1,
-            "Body 2" => data.body = 2,
-            "Body 3" => data.body = 3,
-            _ => {},
+        let Ok(spinner) = spinners.get(event.target) else { continue };
+        data.dirty = true;
+
+        match spinner {
+            CreatorSpinner::Gender => data.gender = event.id.clone(),
+            CreatorSpinner::Body => data.body = event.id.parse().unwrap_or(1),
+            _ => continue,
         }
 
         for mut mesh in &mut query {
-            let new: Handle<Scene> = asset_server.load(&format!("models/{}{}.glb#Scene0", data.gender, data.body));
+            let new: Handle<Scene> = asset_server.load(format!("{}#Scene0", PreLoader::character_model(&data.gender, data.body)));
             *mesh = new;
         }
     }
 }
 
 
+// #=========================#
+// #=== SHOWCASE CONTROLS ===#
+
+/// Seconds without input before the showcase returns to the idle rotation
+const SHOWCASE_IDLE_DELAY: f32 = 5.0;
+/// Radians of model rotation per pixel dragged
+const SHOWCASE_DRAG_SPEED: f32 = 0.01;
+/// Radians per second the right stick rotates the model with
+const SHOWCASE_STICK_SPEED: f32 = 3.0;
+/// How fast the spin of a released drag slows down per second
+const SHOWCASE_SPIN_DAMPING: f32 = 3.0;
+/// Zoom change per wheel line and per second of a fully pressed trigger
+const SHOWCASE_ZOOM_SPEED: f32 = 0.1;
+/// Range of the zoom multiplier of the shot distance
+const SHOWCASE_ZOOM_RANGE: (f32, f32) = (0.5, 1.5);
+/// How fast the camera moves to the shot per second
+const SHOWCASE_CAMERA_SPEED: f32 = 4.0;
+/// Position of the model feet
+const SHOWCASE_BASE: Vec3 = Vec3::new(-0.3, -1.5, -1.0);
+/// Sideways camera offset per unit of distance, keeps the model left of the panel
+const SHOWCASE_FRAMING: f32 = 0.3;
+
+/// Preset camera shots of the showcase
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum ShowcaseShot {
+    #[default]
+    FullBody,
+    Face,
+    Hair,
+}
+impl ShowcaseShot {
+    /// Returns the look height above the feet, the distance and the camera height above the look point per unit of distance
+    fn frame(&self) -> (f32, f32, f32) {
+        match self {
+            ShowcaseShot::FullBody => (0.9, 2.6, 0.1),
+            ShowcaseShot::Face => (1.6, 0.7, 0.0),
+            ShowcaseShot::Hair => (1.7, 0.9, 0.4),
+        }
+    }
+}
+impl From<&CreatorSpinner> for ShowcaseShot {
+    fn from(spinner: &CreatorSpinner) -> Self {
+        match spinner {
+            CreatorSpinner::Gender | CreatorSpinner::Body | CreatorSpinner::Color => ShowcaseShot::FullBody,
+            CreatorSpinner::Hair => ShowcaseShot::Hair,
+            CreatorSpinner::Beard => ShowcaseShot::Face,
+        }
+    }
+}
+
+/// State of the showcase controls, on the camera rendering the model
+#[derive(Component, Debug, Clone, PartialEq)]
+struct ShowcaseCamera {
+    shot: ShowcaseShot,
+    /// Multiplier of the shot distance
+    zoom: f32,
+    /// Rotation of the model in radians
+    yaw: f32,
+    /// Radians per second the model keeps spinning with after a drag
+    spin: f32,
+    /// If the model is being dragged
+    dragging: bool,
+    /// Seconds since the last input
+    idle: f32,
+    /// Seconds of the idle rotation
+    time: f32,
+    /// Smoothed camera position and look point, none until the first frame
+    eye: Option<(Vec3, Vec3)>,
+}
+impl Default for ShowcaseCamera {
+    fn default() -> Self {
+        ShowcaseCamera { shot: ShowcaseShot::default(), zoom: 1.0, yaw: 0.0, spin: 0.0, dragging: false, idle: SHOWCASE_IDLE_DELAY, time: 0.0, eye: None }
+    }
+}
+impl ShowcaseCamera {
+    /// Marks the input, so the idle rotation stops
+    fn wake(&mut self) {
+        self.idle = 0.0;
+    }
+}
+
+/// The UI node displaying the showcase camera
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+struct ShowcaseView {
+    hovered: bool,
+}
+
+/// System that rotates the model by dragging and the right stick and zooms by the wheel and the triggers
+#[allow(clippy::too_many_arguments)]
+fn showcase_input_system(
+    time: Res<Time>,
+    mut wheel: EventReader<MouseWheel>,
+    mut drag_start: EventReader<Pointer<DragStart>>,
+    mut drag: EventReader<Pointer<Drag>>,
+    mut drag_end: EventReader<Pointer<DragEnd>>,
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    gamepad_input: Res<Axis<GamepadAxis>>,
+    gamepad_triggers: Res<Axis<GamepadButton>>,
+    mut views: Query<(Entity, &mut ShowcaseView)>,
+    mut query: Query<&mut ShowcaseCamera>,
+) {
+    let delta = time.delta_seconds();
+    let Ok((view, mut state)) = views.get_single_mut() else { return };
+    for event in over.read() { if event.target == view { state.hovered = true; } }
+    for event in out.read() { if event.target == view { state.hovered = false; } }
+
+    let lines: f32 = wheel.read().map(|event| match event.unit {
+        MouseScrollUnit::Line => event.y,
+        MouseScrollUnit::Pixel => event.y / 40.0,
+    }).sum();
+    let started = drag_start.read().any(|event| event.target == view);
+    let ended = drag_end.read().any(|event| event.target == view);
+    let dragged: f32 = drag.read().filter(|event| event.target == view).map(|event| event.delta.x).sum();
+
+    let gamepad = Gamepad::new(0);
+    let stick = gamepad_input.get(GamepadAxis { gamepad, axis_type: GamepadAxisType::RightStickX }).unwrap_or(0.0);
+    let trigger = gamepad_triggers.get(GamepadButton { gamepad, button_type: GamepadButtonType::RightTrigger2 }).unwrap_or(0.0)
+        - gamepad_triggers.get(GamepadButton { gamepad, button_type: GamepadButtonType::LeftTrigger2 }).unwrap_or(0.0);
+
+    for mut camera in &mut query {
+        if started { camera.dragging = true; }
+
+        // While dragging the model follows the pointer and remembers the speed for the release
+        if camera.dragging {
+            camera.yaw += dragged * SHOWCASE_DRAG_SPEED;
+            camera.spin = if delta > 0.0 { dragged * SHOWCASE_DRAG_SPEED / delta } else { 0.0 };
+            if dragged != 0.0 { camera.wake(); }
+        }
+        if ended { camera.dragging = false; }
+
+        if stick.abs() > 0.1 {
+            camera.yaw += stick * SHOWCASE_STICK_SPEED * delta;
+            camera.spin = 0.0;
+            camera.wake();
+        }
+
+        // Scrolling up and the right trigger move the camera closer
+        let scrolled = if state.hovered { lines * SHOWCASE_ZOOM_SPEED } else { 0.0 };
+        let zoom = scrolled + trigger * SHOWCASE_ZOOM_SPEED * 10.0 * delta;
+        if zoom != 0.0 {
+            camera.zoom = (camera.zoom - zoom).clamp(SHOWCASE_ZOOM_RANGE.0, SHOWCASE_ZOOM_RANGE.1);
+            camera.wake();
+        }
+    }
+}
+
+/// System that picks the shot of the focused or changed spinner
+fn showcase_focus_system(mut events: EventReader<SpinnerChangeEvent>, spinners: Query<&CreatorSpinner>, rows: Query<(&ScrollListRow, &CreatorSpinner), Changed<ScrollListRow>>, mut query: Query<&mut ShowcaseCamera>) {
+    let changed = events.read().filter_map(|event| spinners.get(event.target).ok());
+    let focused = rows.iter().filter(|(row, _)| row.focused).map(|(_, spinner)| spinner);
+    let Some(shot) = changed.chain(focused).last().map(ShowcaseShot::from) else { return };
+    for mut camera in &mut query {
+        camera.shot = shot;
+        camera.wake();
+    }
+}
+
+/// System that spins the model, returns to the idle rotation and moves the camera to the shot
+fn showcase_camera_system(time: Res<Time>, mut cameras: Query<(&mut ShowcaseCamera, &mut Transform), Without<Showcase>>, mut models: Query<&mut Transform, With<Showcase>>) {
+    let delta = time.delta_seconds();
+    for (mut camera, mut transform) in &mut cameras {
+        camera.idle += delta;
+
+        if camera.idle >= SHOWCASE_IDLE_DELAY {
+            // Ease back to the full body shot and the gentle sway, the shortest way around
+            if camera.time == 0.0 { camera.yaw = (camera.yaw + PI).rem_euclid(TAU) - PI; }
+            camera.time += delta;
+            let sway = (20.0 * camera.time.sin()).to_radians();
+            let ease = 1.0 - (-delta).exp();
+            camera.yaw += (sway - camera.yaw) * ease;
+            camera.zoom += (1.0 - camera.zoom) * ease;
+            camera.spin = 0.0;
+            camera.shot = ShowcaseShot::FullBody;
+        } else {
+            camera.time = 0.0;
+            if !camera.dragging {
+                camera.yaw += camera.spin * delta;
+                camera.spin *= (-SHOWCASE_SPIN_DAMPING * delta).exp();
+            }
+        }
+
+        for mut model in &mut models {
+            model.rotation = Quat::from_rotation_y(camera.yaw);
+        }
+
+        // Frame the shot with the model left of the panel
+        let (height, distance, elevation) = camera.shot.frame();
+        let distance = distance * camera.zoom;
+        let offset = Vec3::new(SHOWCASE_FRAMING * distance, 0.0, 0.0);
+        let target = SHOWCASE_BASE + Vec3::Y * height + offset;
+        let eye = target + Vec3::new(0.0, elevation * distance, distance);
+
+        let (current_eye, current_target) = camera.eye.unwrap_or((eye, target));
+        let ease = 1.0 - (-SHOWCASE_CAMERA_SPEED * delta).exp();
+        let (eye, target) = (current_eye.lerp(eye, ease), current_target.lerp(target, ease));
+        camera.eye = Some((eye, target));
+        *transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+    }
+}
+
+
 // #====================#
 // #=== ROUTE PLUGIN ===#
 
@@ -229,9 +504,11 @@ pub struct CharacterCreatorRoutePlugin;
 impl Plugin for CharacterCreatorRoutePlugin {
     fn build(&self, app: &mut App) {
         app
-            .insert_resource(CharacterData { gender: "Female".into(), body: 1})
-            .add_systems(Update, showcase_rotate_system)
-            .add_systems(Update, showcase_swap_system.run_if(on_event::<UiChangeEvent>()))
+            .init_resource::<CharacterData>()
+            .add_systems(Update, (showcase_input_system.run_if(no_dialog_open), showcase_focus_system, showcase_camera_system).chain())
+            .add_systems(Update, showcase_swap_system.run_if(on_event::<SpinnerChangeEvent>()))
+            .add_systems(Update, creator_return_system.run_if(on_event::<UiClickEvent>()).run_if(no_dialog_open))
+            .add_systems(Update, creator_confirm_system.run_if(on_event::<UiClickEvent>()).run_if(no_dialog_open))
 
             .add_systems(PreUpdate, build_route.before(UiSystems::Compute));
     }
@@ -0,0 +1,329 @@
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// When this component is added, a UI system is built
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct CreditsRoute;
+
+/// Event that is emmited when the credits scroll past the top of the screen
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct CreditsFinishedEvent {
+    /// The route entity
+    pub route: Entity,
+}
+
+
+// #=====================#
+// #=== CREDITS ASSET ===#
+
+/// Single line of the credits
+#[derive(Debug, Clone, PartialEq)]
+pub enum CreditsLine {
+    /// `# Heading`
+    Heading(String),
+    /// `## Role`
+    Role(String),
+    /// Plain line
+    Name(String),
+    /// Line of a license included with `!license path`
+    Notice(String),
+    /// Empty line
+    Space,
+}
+impl CreditsLine {
+    /// Parses a single line, `!license` includes are resolved by the loader
+    pub fn parse(line: &str) -> Self {
+        let line = line.trim();
+        if let Some(role) = line.strip_prefix("##") {
+            CreditsLine::Role(role.trim().into())
+        } else if let Some(heading) = line.strip_prefix('#') {
+            CreditsLine::Heading(heading.trim().into())
+        } else if line.is_empty() {
+            CreditsLine::Space
+        } else {
+            CreditsLine::Name(line.into())
+        }
+    }
+    /// Height of the line in % of the screen height
+    fn height(&self) -> f32 {
+        match self {
+            CreditsLine::Heading(_) => 9.0,
+            CreditsLine::Role(_) => 5.0,
+            CreditsLine::Name(_) => 4.5,
+            CreditsLine::Notice(_) => 2.6,
+            CreditsLine::Space => 3.0,
+        }
+    }
+}
+
+/// Credits loaded from a markdown-like `.md` file
+#[derive(Asset, TypePath, Debug, Default, Clone)]
+pub struct Credits {
+    pub lines: Vec<CreditsLine>,
+}
+
+/// Asset loader for the credits, it inlines the license files
+#[derive(Default)]
+struct CreditsLoader;
+impl AssetLoader for CreditsLoader {
+    type Asset = Credits;
+    type Settings = ();
+    type Error = std::io::Error;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), load_context: &'a mut LoadContext<'_>) -> Result<Credits, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8(bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        let mut lines = Vec::new();
+        for line in source.lines() {
+            if let Some(path) = line.trim().strip_prefix("!license") {
+                let bytes = load_context.read_asset_bytes(path.trim().to_string()).await.map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e))?;
+                let license = String::from_utf8(bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                lines.extend(license.lines().map(|line| CreditsLine::Notice(line.trim_end().into())));
+            } else {
+                lines.push(CreditsLine::parse(line));
+            }
+        }
+        Ok(Credits { lines })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["md"]
+    }
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Scrolling state of the route
+#[derive(Component, Debug, Clone, PartialEq)]
+struct CreditsScroll {
+    credits: Handle<Credits>,
+    /// The ui tree entity, lines are spawned into it once the credits load
+    tree: Entity,
+    /// The node holding all lines
+    content: Entity,
+    /// Current scroll offset in % of the screen height
+    scroll: f32,
+    /// Height of all lines, none until the credits are loaded
+    height: Option<f32>,
+    /// Last offset applied to the content layout
+    placed: Option<f32>,
+    finished: bool,
+}
+
+/// Return button of the credits
+#[derive(Component, Debug, Clone, PartialEq)]
+struct CreditsReturnButton {
+    route: Entity,
+}
+
+/// Scroll speed in % of the screen height per second
+const CREDITS_SPEED: f32 = 6.0;
+/// Speed multiplier while the fast forward button is held
+const CREDITS_FAST_FORWARD: f32 = 6.0;
+
+/// System that builds the route
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<CreditsRoute>>) {
+    for route_entity in &query {
+        // #======================#
+        // #=== USER INTERFACE ===#
+
+        let mut tree = None;
+        let mut content = None;
+
+        // Spawn the route
+        commands.entity(route_entity).insert(
+            SpatialBundle::default(),
+        ).with_children(|route| {
+
+            // Spawn the master ui tree
+            tree = Some(route.spawn((
+                UiTreeBundle::<MainUi>::from(UiTree::new2d("Credits")),
+                SourceFromCamera,
+            )).with_children(|ui| {
+
+                // Spawn the root div
+                let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
+                ui.spawn((
+                    root.clone(),                           // Here we add the link
+                    UiLayout::window_full().pack::<Base>(),         // This is where we define layout
+                ));
+
+                // Spawn the background
+                ui.spawn((
+                    root.add("Background"), // You can see here that we used existing "root" link to create chained link (same as "Root/Background")
+                    UiLayout::solid().size((2968.0, 1656.0)).scaling(Scaling::Fill).pack::<Base>(),
+                    UiImage2dBundle::from(assets.load(PreLoader::SETTINGS_BACKGROUND)),  // We use this bundle to add background image to our node
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the scrolling content, it starts below the screen
+                content = Some(ui.spawn((
+                    root.add("Content"),
+                    UiLayout::window().y(Rl(100.0)).size(Rl(100.0)).pack::<Base>(),
+                )).id());
+
+                // Spawn return button
+                ui.spawn((
+                    root.add("Return"),
+                    UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack::<Base>(),
+                    Button { text: "common-return".into(), ..default() },
+                    WidgetSounds::back(),
+
+                    // Returns to the route the credits were opened from
+                    CreditsReturnButton { route: route_entity },
+                ));
+            }).id());
+        });
+
+        if let (Some(tree), Some(content)) = (tree, content) {
+            commands.entity(route_entity).insert(CreditsScroll {
+                credits: assets.load(PreLoader::CREDITS),
+                tree,
+                content,
+                scroll: 0.0,
+                height: None,
+                placed: None,
+                finished: false,
+            });
+        }
+    }
+}
+
+/// System that spawns the lines once the credits are loaded
+fn credits_populate_system(mut commands: Commands, assets: Res<AssetServer>, credits: Res<Assets<Credits>>, mut query: Query<&mut CreditsScroll>) {
+    for mut state in &mut query {
+        if state.height.is_some() { continue; }
+        let Some(credits) = credits.get(&state.credits) else { continue };
+
+        let mut y = 0.0;
+        commands.entity(state.tree).with_children(|ui| {
+            for (index, line) in credits.lines.iter().enumerate() {
+                let (text, font, color, size) = match line {
+                    CreditsLine::Heading(text) => (text, PreLoader::FONT_SEMIBOLD, Color::BEVYPUNK_YELLOW.with_alpha(1.2), 6.0),
+                    CreditsLine::Role(text) => (text, PreLoader::FONT_MEDIUM, Color::BEVYPUNK_RED_DIM, 3.2),
+                    CreditsLine::Name(text) => (text, PreLoader::FONT_REGULAR, Color::BEVYPUNK_RED, 3.6),
+                    CreditsLine::Notice(text) => (text, PreLoader::FONT_LIGHT, Color::BEVYPUNK_RED_DIM, 2.0),
+                    CreditsLine::Space => { y += line.height(); continue; },
+                };
+                if !text.is_empty() {
+                    ui.spawn((
+                        UiLink::<MainUi>::path(format!("Root/Content/Line{index}")),
+                        UiLayout::window().pos(Rl((50.0, y))).anchor(Anchor::TopCenter).pack::<Base>(),
+                        UiText2dBundle {
+                            text: Text::from_section(text.clone(),
+                                TextStyle {
+                                    font: assets.load(font),
+                                    font_size: 60.0,
+                                    color,
+                                }),
+                            ..default()
+                        },
+                        UiTextSize::new().size(Rh(size)),
+                        Pickable::IGNORE,
+                    ));
+                }
+                y += line.height();
+            }
+        });
+        state.height = Some(y);
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that scrolls the credits, faster while a button is held
+fn credits_scroll_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    console: Res<Console>,
+    mut query: Query<(Entity, &mut CreditsScroll)>,
+    mut finished: EventWriter<CreditsFinishedEvent>,
+) {
+    // Keys typed into the console don't fast forward
+    let no_keys = ButtonInput::default();
+    let keyboard = if console.open { &no_keys } else { &*keyboard };
+    let pad = |button| gamepad.pressed(GamepadButton::new(Gamepad::new(0), button));
+    let fast = keyboard.any_pressed([KeyCode::Space, KeyCode::Enter, KeyCode::ArrowDown]) || mouse.pressed(MouseButton::Left)
+        || pad(GamepadButtonType::South) || pad(GamepadButtonType::DPadDown);
+    let speed = CREDITS_SPEED * if fast { CREDITS_FAST_FORWARD } else { 1.0 };
+
+    for (route, mut state) in &mut query {
+        let Some(height) = state.height else { continue };
+        if state.finished { continue; }
+
+        state.scroll += speed * time.delta_seconds();
+        let offset = 100.0 - state.scroll;
+        if state.placed != Some(offset) {
+            state.placed = Some(offset);
+            commands.entity(state.content).insert(UiLayout::window().y(Rl(offset)).size(Rl(100.0)).pack::<Base>());
+        }
+
+        // The last line left the top of the screen
+        if state.scroll >= height + 100.0 {
+            state.finished = true;
+            finished.send(CreditsFinishedEvent { route });
+        }
+    }
+}
+
+/// System that returns to the previous route on Escape, B, the return button and when the credits finish
+#[allow(clippy::too_many_arguments)]
+fn credits_return_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    console: Res<Console>,
+    dialogs: Query<(), With<Dialog>>,
+    history: Res<RouteHistory>,
+    mut finished: EventReader<CreditsFinishedEvent>,
+    mut clicks: EventReader<UiClickEvent>,
+    buttons: Query<&CreditsReturnButton>,
+    query: Query<Entity, With<CreditsRoute>>,
+) {
+    // The events are read every frame, only the back keys belong to the console or the open dialog
+    let blocked = console.open || !dialogs.is_empty();
+    let back = !blocked && (keyboard.just_pressed(KeyCode::Escape) || gamepad.just_pressed(GamepadButton::new(Gamepad::new(0), GamepadButtonType::East)));
+    let mut routes: Vec<Entity> = finished.read().map(|event| event.route).collect();
+    routes.extend(clicks.read().filter_map(|event| buttons.get(event.target).ok()).map(|button| button.route));
+    if back { routes.extend(query.iter()); }
+    routes.sort();
+    routes.dedup();
+
+    // Started straight into the credits, there is nothing to return to but the menu
+    let previous = history.previous.filter(|route| *route != AppRoute::Credits && *route != AppRoute::Intro).unwrap_or(AppRoute::MainMenu);
+    for route in routes {
+        if query.contains(route) {
+            commands.entity(route).despawn_recursive();
+            commands.spawn(LoadingRoute::new(previous));
+        }
+    }
+}
+
+
+// #====================#
+// #=== ROUTE PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct CreditsRoutePlugin;
+impl Plugin for CreditsRoutePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<Credits>()
+            .init_asset_loader::<CreditsLoader>()
+            .add_event::<CreditsFinishedEvent>()
+
+            .add_systems(PreUpdate, build_route.before(UiSystems::Compute))
+            .add_systems(Update, (credits_populate_system, credits_scroll_system, credits_return_system).chain().before(UiSystems::Compute));
+    }
+}
@@ -1,21 +1,30 @@
 use avian3d::{math::*, prelude::*};
 use bevy::{ecs::query::Has, prelude::*};
+use crate::{console_closed, Tunable, TunableAppExt};
 
 pub struct CharacterControllerPlugin;
 
 impl Plugin for CharacterControllerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<MovementAction>().add_systems(
-            Update,
-            (
-                keyboard_input,
-                gamepad_input,
-                update_grounded,
-                movement,
-                apply_movement_damping,
-            )
-                .chain(),
-        );
+        app.add_event::<MovementAction>()
+            .register_tunables::<ControllerTuning>(vec![
+                Tunable::new("acceleration", "tuning-controller-acceleration", 0.0, 100.0).step(1.0),
+                Tunable::new("damping", "tuning-controller-damping", 0.5, 1.0).step(0.01),
+                Tunable::new("jump_impulse", "tuning-controller-jump-impulse", 0.0, 20.0).step(0.1),
+                Tunable::new("acceleration_multiplier", "tuning-controller-acceleration-multiplier", 0.0, 10.0).step(0.1),
+            ])
+            .add_systems(
+                Update,
+                (
+                    apply_controller_tuning.run_if(resource_changed::<ControllerTuning>),
+                    keyboard_input.run_if(console_closed),
+                    gamepad_input,
+                    update_grounded,
+                    movement,
+                    apply_movement_damping,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -26,6 +35,28 @@ pub enum MovementAction {
     Jump,
 }
 
+/// Feel of the character controllers, editable at runtime from the tuning panel.
+/// Changing it overwrites the movement values of all existing controllers.
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct ControllerTuning {
+    pub acceleration: Scalar,
+    pub damping: Scalar,
+    pub jump_impulse: Scalar,
+    /// Multiplier of the acceleration applied to the move input
+    pub acceleration_multiplier: Scalar,
+}
+impl Default for ControllerTuning {
+    fn default() -> Self {
+        Self {
+            acceleration: 30.0,
+            damping: 0.9,
+            jump_impulse: 7.0,
+            acceleration_multiplier: 3.0,
+        }
+    }
+}
+
 /// A marker component indicating that an entity is using a character controller.
 #[derive(Component)]
 pub struct CharacterController;
@@ -91,7 +122,8 @@ impl MovementBundle {
 
 impl Default for MovementBundle {
     fn default() -> Self {
-        Self::new(30.0, 0.9, 7.0, PI * 0.45)
+        let tuning = ControllerTuning::default();
+        Self::new(tuning.acceleration, tuning.damping, tuning.jump_impulse, PI * 0.45)
     }
 }
 
@@ -129,6 +161,18 @@ impl CharacterControllerBundle {
     }
 }
 
+/// Applies the [`ControllerTuning`] to all character controllers.
+fn apply_controller_tuning(
+    tuning: Res<ControllerTuning>,
+    mut query: Query<(&mut MovementAcceleration, &mut MovementDampingFactor, &mut JumpImpulse)>,
+) {
+    for (mut acceleration, mut damping, mut jump_impulse) in &mut query {
+        acceleration.0 = tuning.acceleration;
+        damping.0 = tuning.damping;
+        jump_impulse.0 = tuning.jump_impulse;
+    }
+}
+
 /// Sends [`MovementAction`] events based on keyboard input.
 fn keyboard_input(
     mut movement_event_writer: EventWriter<MovementAction>,
@@ -216,6 +260,7 @@ fn update_grounded(
 /// Responds to [`MovementAction`] events and moves character controllers accordingly.
 fn movement(
     time: Res<Time>,
+    tuning: Res<ControllerTuning>,
     mut movement_event_reader: EventReader<MovementAction>,
     mut controllers: Query<(
         &MovementAcceleration,
@@ -234,8 +279,8 @@ fn movement(
         {
             match event {
                 MovementAction::Move(direction) => {
-                    linear_velocity.x += direction.x * movement_acceleration.0 * delta_time * 3.0;
-                    linear_velocity.z -= direction.y * movement_acceleration.0 * delta_time * 3.0;
+                    linear_velocity.x += direction.x * movement_acceleration.0 * delta_time * tuning.acceleration_multiplier;
+                    linear_velocity.z -= direction.y * movement_acceleration.0 * delta_time * tuning.acceleration_multiplier;
                 }
                 MovementAction::Jump => {
                     if is_grounded {
@@ -78,6 +78,54 @@ fn player_input(
 }
 
 
+// #=====================#
+// #=== PLAYER TUNING ===#
+
+/// Movement feel of the player, editable at runtime from the tuning panel
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct PlayerTuning {
+    pub prone_speed: f32,           // ~1 m/s
+    pub crouch_speed: f32,          // 1.5-2.5 m/s
+    pub ads_speed: f32,             // 2-3 m/s
+    pub base_speed: f32,            // 3-4 m/s
+    pub running_speed: f32,         // 5-8 m/s
+    pub sprinting_speed: f32,       // ~8m/s
+    pub strafing_multiplier: f32,   // 70-90 %
+    pub backwards_multiplier: f32,  // 50-80 %
+    /// How fast the velocity follows the input
+    pub acceleration: f32,
+}
+impl Default for PlayerTuning {
+    fn default() -> Self {
+        PlayerTuning {
+            prone_speed: 1.0,
+            crouch_speed: 2.0,
+            ads_speed: 2.5,
+            base_speed: 3.5,
+            running_speed: 7.0,
+            sprinting_speed: 8.0,
+            strafing_multiplier: 0.85,
+            backwards_multiplier: 0.75,
+            acceleration: 4.0,
+        }
+    }
+}
+impl PlayerTuning {
+    /// Returns the movement speed of the state
+    pub fn speed(&self, state: &PlayerState) -> f32 {
+        match state {
+            PlayerState::Sprinting => self.sprinting_speed,
+            PlayerState::Running => self.running_speed,
+            PlayerState::Base => self.base_speed,
+            PlayerState::ADS => self.ads_speed,
+            PlayerState::Crouch => self.crouch_speed,
+            PlayerState::Prone => self.prone_speed,
+        }
+    }
+}
+
+
 // #=======================#
 // #=== PLAYER MOVEMENT ===#
 
@@ -121,7 +169,7 @@ fn player_tilt_rotation(
 }
 
 
-#[derive(Component, Default, Debug, PartialEq)]
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
 pub enum PlayerState {
     Sprinting,
     Running,
@@ -174,6 +222,7 @@ fn player_state(
 
 fn player_movement(
     time: Res<Time>,
+    tuning: Res<PlayerTuning>,
     mut player_move: EventReader<PlayerMove>,
     mut query: Query<(&PlayerState, &PlayerPlaneRotation, &mut LinearVelocity)>,
 ) {
@@ -181,19 +230,12 @@ fn player_movement(
         for (state, rotation, mut physics) in &mut query {
 
             // Get the proper movement speed
-            let movement_speed = match *state {
-                PlayerState::Sprinting => SPRINTING_MOVEMENT_SPEED,
-                PlayerState::Running => RUNNING_MOVEMENT_SPEED,
-                PlayerState::Base => BASE_MOVEMENT_SPEED,
-                PlayerState::ADS => ADS_MOVEMENT_SPEED,
-                PlayerState::Crouch => CROUCH_MOVEMENT_SPEED,
-                PlayerState::Prone => PRONE_MOVEMENT_SPEED,
-            };
+            let movement_speed = tuning.speed(state);
     
             // Compute the direction offsets
             let local = Vec2 {
-                x: event.x * movement_speed * 1.0.lerp(BACKWARDS_MOVEMEMENT_SPEED_MULTIPLIER, event.x.abs() * event.x.is_sign_negative() as i8 as f32 ),
-                y: event.y * movement_speed * 1.0.lerp(STRAFING_MOVEMEMENT_SPEED_MULTIPLIER, event.y.abs())
+                x: event.x * movement_speed * 1.0.lerp(tuning.backwards_multiplier, event.x.abs() * event.x.is_sign_negative() as i8 as f32 ),
+                y: event.y * movement_speed * 1.0.lerp(tuning.strafing_multiplier, event.y.abs())
             };
     
             // Compute two perpendicular vectors for global transformation
@@ -205,12 +247,142 @@ fn player_movement(
             global += front_vector * local.x;
             global += right_vector * local.y;
     
-            physics.x += global.x * time.delta_seconds() * 4.0;
-            physics.z += global.y * time.delta_seconds() * 4.0;
+            physics.x += global.x * time.delta_seconds() * tuning.acceleration;
+            physics.z += global.y * time.delta_seconds() * tuning.acceleration;
+        }
+    }
+}
+
+// #========================#
+// #=== PLAYER HEAD BOB ===#
+
+/// Camera motion layer bobbing the camera with the steps of the player
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct HeadBob {
+    /// Meters walked per two steps
+    pub stride: f32,
+    /// Vertical bob in meters
+    pub vertical: f32,
+    /// Sideways sway in meters
+    pub sideways: f32,
+    /// Roll in radians
+    pub roll: f32,
+    phase: f32,
+    /// Smoothed strength, so the bob fades in and out
+    strength: f32,
+}
+impl Default for HeadBob {
+    fn default() -> Self {
+        HeadBob { stride: 1.6, vertical: 0.04, sideways: 0.03, roll: 0.01, phase: 0.0, strength: 0.0 }
+    }
+}
+
+/// Returns how strongly the head bobs in the state
+fn head_bob_strength(state: &PlayerState) -> f32 {
+    match state {
+        PlayerState::Sprinting => 1.6,
+        PlayerState::Running => 1.3,
+        PlayerState::Base => 1.0,
+        PlayerState::ADS => 0.3,
+        PlayerState::Crouch => 0.7,
+        PlayerState::Prone => 0.4,
+    }
+}
+
+// This function will bob the camera while the player moves
+fn player_head_bob(
+    time: Res<Time>,
+    tuning: Res<PlayerTuning>,
+    player: Query<(&PlayerState, &LinearVelocity)>,
+    mut query: Query<(&mut HeadBob, &mut CameraMotion)>,
+) {
+    let Ok((state, velocity)) = player.get_single() else { return };
+    let speed = Vec2::new(velocity.x, velocity.z).length();
+    let target = (speed / tuning.base_speed.max(0.1)).min(1.0) * head_bob_strength(state);
+
+    for (mut bob, mut motion) in &mut query {
+        bob.phase = (bob.phase + speed * time.delta_seconds() / bob.stride.max(0.1) * std::f32::consts::TAU) % std::f32::consts::TAU;
+        bob.strength += (target - bob.strength) * (1.0 - (-8.0 * time.delta_seconds()).exp());
+
+        motion.add(MotionAxis::Y, (bob.phase * 2.0).sin() * bob.vertical * bob.strength);
+        motion.add(MotionAxis::X, bob.phase.sin() * bob.sideways * bob.strength);
+        motion.add(MotionAxis::Roll, bob.phase.sin() * bob.roll * bob.strength);
+    }
+}
+
+
+// #========================#
+// #=== PLAYER FOOTSTEPS ===#
+
+/// Add this component to the player to play footsteps and landings of the surface below
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Footsteps {
+    /// Meters walked per step
+    pub stride: f32,
+    /// How far below the center the ground is looked for
+    pub reach: f32,
+    /// Falling speed from which landing plays at full volume
+    pub landing_speed: f32,
+    distance: f32,
+    step: usize,
+    /// Falling speed of the last frame in the air, none while grounded
+    falling: Option<f32>,
+}
+impl Default for Footsteps {
+    fn default() -> Self {
+        Footsteps { stride: 0.8, reach: 0.75, landing_speed: 8.0, distance: 0.0, step: 0, falling: None }
+    }
+}
+
+/// Returns how loud the steps are in the state
+fn footstep_volume(state: &PlayerState) -> f64 {
+    match state {
+        PlayerState::Sprinting => 1.0,
+        PlayerState::Running => 0.9,
+        PlayerState::Base => 0.7,
+        PlayerState::ADS => 0.5,
+        PlayerState::Crouch => 0.35,
+        PlayerState::Prone => 0.2,
+    }
+}
+
+// This function will play the steps and landings on the surface below the player
+fn player_footsteps(
+    time: Res<Time>,
+    audio: AudioBuses,
+    assets: Res<AssetServer>,
+    spatial: SpatialQuery,
+    surfaces: Query<&SurfaceTag>,
+    mut query: Query<(Entity, &GlobalTransform, &PlayerState, &LinearVelocity, &mut Footsteps)>,
+) {
+    for (entity, transform, state, velocity, mut steps) in &mut query {
+        let ground = spatial.cast_ray(transform.translation(), Dir3::NEG_Y, steps.reach, true, SpatialQueryFilter::from_excluded_entities([entity]));
+        let Some(ground) = ground else {
+            steps.falling = Some(-velocity.y);
+            continue;
+        };
+        let surface = surfaces.get(ground.entity).copied().unwrap_or_default();
+
+        // Land with the speed the player was falling at
+        if let Some(falling) = steps.falling.take() {
+            if falling > 1.0 {
+                audio.play_with_volume(AudioBus::Sfx, assets.load(surface.landing()), (falling / steps.landing_speed).min(1.0) as f64);
+                steps.distance = 0.0;
+            }
+        }
+
+        // Step every stride walked
+        steps.distance += Vec2::new(velocity.x, velocity.z).length() * time.delta_seconds();
+        if steps.distance >= steps.stride {
+            steps.distance %= steps.stride;
+            steps.step += 1;
+            let clips = surface.footsteps();
+            audio.play_with_volume(AudioBus::Sfx, assets.load(clips[steps.step % clips.len()]), footstep_volume(state));
         }
     }
 }
 
+
 // #=====================#
 // #=== PLAYER PLUGIN ===#
 
@@ -221,26 +393,30 @@ impl Plugin for PlayerPlugin {
             .add_event::<PlayerMove>()
             .add_event::<PlayerLook>()
             .add_event::<PlayerAct>()
+            .register_tunables::<PlayerTuning>(vec![
+                Tunable::new("prone_speed", "tuning-prone-speed", 0.0, 10.0).step(0.1),
+                Tunable::new("crouch_speed", "tuning-crouch-speed", 0.0, 10.0).step(0.1),
+                Tunable::new("ads_speed", "tuning-ads-speed", 0.0, 10.0).step(0.1),
+                Tunable::new("base_speed", "tuning-base-speed", 0.0, 10.0).step(0.1),
+                Tunable::new("running_speed", "tuning-running-speed", 0.0, 15.0).step(0.1),
+                Tunable::new("sprinting_speed", "tuning-sprinting-speed", 0.0, 15.0).step(0.1),
+                Tunable::new("strafing_multiplier", "tuning-strafing-multiplier", 0.0, 1.0).step(0.05),
+                Tunable::new("backwards_multiplier", "tuning-backwards-multiplier", 0.0, 1.0).step(0.05),
+                Tunable::new("acceleration", "tuning-player-acceleration", 0.0, 10.0).step(0.1),
+            ])
             .add_systems(Update, (
-                player_input,
+                player_input.run_if(console_closed),
                 player_plane_rotation.run_if(on_event::<PlayerLook>()),
                 player_tilt_rotation.run_if(on_event::<PlayerLook>()),
                 player_state.run_if(on_event::<PlayerAct>()),
                 player_movement.run_if(on_event::<PlayerMove>()),
-            ).chain());
+            ).chain())
+            .add_systems(Update, player_head_bob.in_set(CameraMotionSystems::Layers))
+            .add_systems(Update, player_footsteps);
     }
 }
 
 
 ///////////////////////////////////////////////////////////////////////////////////////
 
-pub const PRONE_MOVEMENT_SPEED: f32 = 1.0;      // ~1 m/s
-pub const CROUCH_MOVEMENT_SPEED: f32 = 2.0;     // 1.5-2.5 m/s
-pub const ADS_MOVEMENT_SPEED: f32 = 2.5;        // 2-3 m/s
-pub const BASE_MOVEMENT_SPEED: f32 = 3.5;       // 3-4 m/s
-pub const RUNNING_MOVEMENT_SPEED: f32 = 7.0;    // 5-8 m/s
-pub const SPRINTING_MOVEMENT_SPEED: f32 = 8.0;  // ~8m/s
-pub const STRAFING_MOVEMEMENT_SPEED_MULTIPLIER: f32 = 0.85;    // 70-90 %
-pub const BACKWARDS_MOVEMEMENT_SPEED_MULTIPLIER: f32 = 0.75;  // 50-80 %
 pub const HALF_PI: f32 = std::f32::consts::PI / 2.0;
-
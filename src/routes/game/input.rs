@@ -47,6 +47,6 @@ impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app
             .insert_resource(MouseCapture::default())
-            .add_systems(Update, (update_mouse_capture, switch_mouse_capture_focus));
+            .add_systems(Update, (update_mouse_capture, switch_mouse_capture_focus.run_if(console_closed)));
     }
 }
@@ -13,6 +13,9 @@ pub use route::*;
 pub mod input;
 pub use input::*;
 
+pub mod spatial_sound;
+pub use spatial_sound::*;
+
 
 // #====================#
 // #=== ROUTE PLUGIN ===#
@@ -26,6 +29,7 @@ impl Plugin for GamePlugin {
             .add_plugins(EntryPlugin)
             .add_plugins(PlayerPlugin)
             .add_plugins(CharacterControllerPlugin)
+            .add_plugins(SpatialSoundPlugin)
             .add_plugins(PhysicsPlugins::default());
     }
 }
@@ -16,15 +16,16 @@ pub struct GameRoute;
 // #=== SANDBOXED USER INTEFACE ===#
 
 /// System that builds the route
-fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Query<Entity, Added<GameRoute>>, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>, flicker: Query<Entity, With<VFXBloomFlicker>>) {
+fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Query<Entity, Added<GameRoute>>, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>) {
     for route_entity in &query {
         // #======================#
         // #=== USER INTERFACE ===#
 
-        // Disable flickering
-        if let Ok(entity) = flicker.get_single() {
-            commands.entity(entity).remove::<VFXBloomFlicker>();
-        }
+        // Calm down the flickering while playing
+        commands.entity(route_entity).insert(BloomFlickerProfile::CALM);
+
+        // Play the music quieter under the gameplay
+        commands.entity(route_entity).insert(MusicPlaylist::new(vec![asset_server.load(PreLoader::MUSIC_INTRO), asset_server.load(PreLoader::MUSIC)]).volume(0.6));
         
         // Render 3D camera onto a texture
         let size = Extent3d { width: 1920, height: 1080, ..default() };
@@ -58,40 +59,26 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 ..default()
             }); */
 
-            // Spawn balls
-            /* for y in 5..6 {
-                for x in -2..2 {
-                    for z in -2..2 {
-                        route.spawn((
-                            RigidBody::Dynamic,
-                            Velocity::linear(Vec3::new(0.0, 1.0, 0.0)),
-                            Collider::ball(0.3 + 0.1 * x as f32),
-                            Restitution::coefficient(0.7),
-                            PbrBundle {
-                                mesh: meshes.add(Sphere::new(0.3 + 0.1 * x as f32).mesh().ico(5).unwrap()),
-                                material: materials.add(StandardMaterial {
-                                    //emissive: LinearRgba::rgb(3.0, 23.0, 9.0) * 0.1,
-                                    emissive: Color::BEVYPUNK_YELLOW.into(),
-                                    ..default()
-                                }),
-                                transform: Transform::from_xyz(x as f32, y as f32, z as f32),
-                                ..default()
-                            }
-                        )).with_children(|obj| {
-                            obj.spawn(PointLightBundle {
-                                point_light: PointLight {
-                                    intensity: 1.0,
-                                    shadows_enabled: false,
-                                    //color: (LinearRgba::rgb(3.0, 23.0, 9.0) * 0.1).into(),
-                                    color: Color::BEVYPUNK_YELLOW.into(),
-                                    ..default()
-                                },
-                                ..default()
-                            });
-                        });
-                    }
-                }
-            } */
+            // Spawn the ambient particles
+            route.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(0.0, 15.0, 0.0)),
+                ParticleEmitter::new3d(asset_server.load(PreLoader::PARTICLES_RAIN)),
+            ));
+            route.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(-8.0, 4.0, -23.5)),
+                ParticleEmitter::new3d(asset_server.load(PreLoader::PARTICLES_SPARKS)),
+                SpatialSound::new(asset_server.load(PreLoader::SFX_NEON_BUZZ)).volume(0.6).range(1.0, 12.0),
+            ));
+            route.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(10.0, 0.0, -22.0)),
+                ParticleEmitter::new3d(asset_server.load(PreLoader::PARTICLES_STEAM)),
+            ));
+
+            // Spawn the distant city, it is heard from everywhere in the yard
+            route.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(0.0, 30.0, -60.0)),
+                SpatialSound::new(asset_server.load(PreLoader::SFX_CITY)).volume(0.5).range(70.0, 150.0),
+            ));
 
             // Spawn player
             route.spawn((
@@ -118,6 +105,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 //ControllerInput::default(),
                 PlayerPlaneRotation::default(),
                 PlayerState::default(),
+                Footsteps::default(),
                 MovementDampingFactor(0.99),
                 //ControllerGravity::default(),
 
@@ -138,41 +126,63 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
 
                         let light = 50.0;
 
-                        // Spawn camera
-                        let mut cam = obj.spawn((
-                            BloomSettings::NATURAL,
-                            Skybox {
-                                image: asset_server.load("scenes/skybox/skybox.ktx2"),
-                                brightness: light,
-                            },
-                            EnvironmentMapLight {
-                                diffuse_map: asset_server.load("scenes/skybox/diffuse_map.ktx2"),
-                                specular_map: asset_server.load("scenes/skybox/specular_map.ktx2"),
-                                intensity: light,
-                            },
-                            Camera3dBundle {
-                                camera: Camera {
-                                    order: -1,
-                                    target: render_image.clone().into(),
-                                    //clear_color: ClearColorConfig::Custom(Color::srgba(0.0, 0.0, 0.0, 0.0)),
-                                    hdr: true,
-                                    ..default()
-                                },
-                                projection: Projection::Perspective(PerspectiveProjection {
-                                    fov: 60.0_f32.to_radians(),
-                                    ..default()
-                                }),
-                                ..default()
-                            },
-                            VisibilityBundle::default(),
+                        // Spawn the tilt, the camera motion is added on top of it
+                        obj.spawn((
+                            SpatialBundle::default(),
                             PlayerTiltRotation::default(),
-                        ));
+                        )).with_children(|obj| {
 
-                        #[cfg(not(target_family = "wasm"))]
-                        cam.insert((
-                            TemporalAntiAliasBundle::default(),
-                            ContrastAdaptiveSharpeningSettings::default(),
-                        ));
+                            // Spawn camera
+                            let mut cam = obj.spawn((
+                                BloomSettings::NATURAL,
+                                Skybox {
+                                    image: asset_server.load(PreLoader::SKYBOX),
+                                    brightness: light,
+                                },
+                                EnvironmentMapLight {
+                                    diffuse_map: asset_server.load(PreLoader::SKYBOX_DIFFUSE),
+                                    specular_map: asset_server.load(PreLoader::SKYBOX_SPECULAR),
+                                    intensity: light,
+                                },
+                                Camera3dBundle {
+                                    camera: Camera {
+                                        order: -1,
+                                        target: render_image.clone().into(),
+                                        //clear_color: ClearColorConfig::Custom(Color::srgba(0.0, 0.0, 0.0, 0.0)),
+                                        hdr: true,
+                                        ..default()
+                                    },
+                                    projection: Projection::Perspective(PerspectiveProjection {
+                                        fov: 60.0_f32.to_radians(),
+                                        ..default()
+                                    }),
+                                    ..default()
+                                },
+                                VisibilityBundle::default(),
+
+                                // The spatial sounds are heard from the camera
+                                SoundListener,
+
+                                // Screen effects that warp the picture
+                                BarrelDistortion::default(),
+                                GlitchPulse::default(),
+
+                                // Procedural motion layers
+                                CameraMotion::default(),
+                                CameraShake::default(),
+                                HeadBob::default(),
+                                CameraWiggle::new(vec![
+                                    Wiggle::Noise { axis: MotionAxis::Pitch, frequency: 0.2, amplitude: 0.004, seed: 1 },
+                                    Wiggle::Noise { axis: MotionAxis::Yaw, frequency: 0.15, amplitude: 0.004, seed: 2 },
+                                ]),
+                            ));
+
+                            #[cfg(not(target_family = "wasm"))]
+                            cam.insert((
+                                TemporalAntiAliasBundle::default(),
+                                ContrastAdaptiveSharpeningSettings::default(),
+                            ));
+                        });
 
                     });
                 });
@@ -192,6 +202,31 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 },
                 Collider::cuboid(50.0, 2.0, 50.0),
                 RigidBody::Static,
+                SurfaceTag::Concrete,
+            ));
+
+            // Spawn the metal catwalk and the puddle, so the steps change sound
+            route.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Cuboid::new(6.0, 0.1, 6.0)),
+                    material: materials.add(Color::srgb_u8(90, 95, 105)),
+                    transform: Transform::from_xyz(8.0, 0.05, -10.0),
+                    ..default()
+                },
+                Collider::cuboid(6.0, 0.1, 6.0),
+                RigidBody::Static,
+                SurfaceTag::Metal,
+            ));
+            route.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Cuboid::new(4.0, 0.02, 4.0)),
+                    material: materials.add(StandardMaterial { base_color: Color::srgb_u8(20, 25, 35), perceptual_roughness: 0.05, ..default() }),
+                    transform: Transform::from_xyz(-6.0, 0.01, -8.0),
+                    ..default()
+                },
+                Collider::cuboid(4.0, 0.02, 4.0),
+                RigidBody::Static,
+                SurfaceTag::Water,
             ));
             route.spawn((
                 PbrBundle {
@@ -202,6 +237,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 },
                 Collider::cuboid(50.0, 2.0, 2.0),
                 RigidBody::Static,
+                SurfaceTag::Metal,
             ));
             route.spawn((
                 PbrBundle {
@@ -212,6 +248,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 },
                 Collider::cuboid(50.0, 2.0, 2.0),
                 RigidBody::Static,
+                SurfaceTag::Metal,
             ));
             route.spawn((
                 PbrBundle {
@@ -222,6 +259,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 },
                 Collider::cuboid(2.0, 2.0, 50.0),
                 RigidBody::Static,
+                SurfaceTag::Metal,
             ));
             route.spawn((
                 PbrBundle {
@@ -232,6 +270,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 },
                 Collider::cuboid(2.0, 2.0, 50.0),
                 RigidBody::Static,
+                SurfaceTag::Metal,
             ));
 
             route.spawn((
@@ -240,6 +279,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                     tree: UiTree::new3d("Worldspace"),
                     ..default()
                 },
+                SpatialSound::new(asset_server.load(PreLoader::SFX_TERMINAL)).bus(AudioBus::Sfx).volume(0.4).range(0.5, 6.0),
             )).with_children(|ui|{
                 ui.spawn((
                     UiLink::<Ui3d>::path("Display"),
@@ -253,8 +293,8 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                     UiAnimator::<Hover>::new().forward_speed(6.0).backward_speed(6.0),
                     OnHoverSetCursor::new(CursorIcon::Pointer),
 
-                    //UiMaterial3dBundle::from_image(&mut material, asset_server.load("images/hud/hud.png")),
-                    UiMaterial3dBundle::from_transparent_image(&mut materials, asset_server.load("images/hud/hud.png")),
+                    //UiMaterial3dBundle::from_image(&mut material, asset_server.load(PreLoader::HUD)),
+                    UiMaterial3dBundle::from_transparent_image(&mut materials, asset_server.load(PreLoader::HUD)),
                 ));
             });
 
@@ -277,8 +317,8 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                     UiAnimator::<Hover>::new().forward_speed(6.0).backward_speed(6.0),
                     OnHoverSetCursor::new(CursorIcon::Pointer),
 
-                    //UiMaterial3dBundle::from_image(&mut material, asset_server.load("images/hud/hud.png")),
-                    UiMaterial3dBundle::from_transparent_image(&mut materials, asset_server.load("images/hud/hud.png")),
+                    //UiMaterial3dBundle::from_image(&mut material, asset_server.load(PreLoader::HUD)),
+                    UiMaterial3dBundle::from_transparent_image(&mut materials, asset_server.load(PreLoader::HUD)),
                 ));
             });
 
@@ -300,7 +340,7 @@ fn build_route(mut commands: Commands, asset_server: Res<AssetServer>, query: Qu
                 ui.spawn((
                     UiLink::<MainUi>::path("Camera/HUD"),
                     UiLayout::solid().size((1920.0, 1080.0)).scaling(Scaling::Fit).pack::<Base>(),
-                    UiImage2dBundle::from(asset_server.load("images/hud/hud.png")),
+                    UiImage2dBundle::from(asset_server.load(PreLoader::HUD)),
                     Pickable::IGNORE,
                 ));
 
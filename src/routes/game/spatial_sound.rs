@@ -0,0 +1,181 @@
+use std::time::Duration;
+use bevy::utils::HashMap;
+use avian3d::prelude::*;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Add this component to a collider to pick the footstep and landing sounds of the surface.
+/// Colliders without the tag sound like concrete.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SurfaceTag {
+    #[default]
+    Concrete,
+    Metal,
+    Water,
+}
+impl SurfaceTag {
+    pub const ALL: [SurfaceTag; 3] = [SurfaceTag::Concrete, SurfaceTag::Metal, SurfaceTag::Water];
+
+    /// Footstep clips of the surface, played in turns
+    pub fn footsteps(&self) -> [&'static str; 2] {
+        match self {
+            SurfaceTag::Concrete => PreLoader::FOOTSTEPS_CONCRETE,
+            SurfaceTag::Metal => PreLoader::FOOTSTEPS_METAL,
+            SurfaceTag::Water => PreLoader::FOOTSTEPS_WATER,
+        }
+    }
+    /// Landing clip of the surface
+    pub fn landing(&self) -> &'static str {
+        match self {
+            SurfaceTag::Concrete => PreLoader::LANDING_CONCRETE,
+            SurfaceTag::Metal => PreLoader::LANDING_METAL,
+            SurfaceTag::Water => PreLoader::LANDING_WATER,
+        }
+    }
+}
+
+/// Add this component to the camera the [`SpatialSound`]s are heard from
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct SoundListener;
+
+/// Add this component to a world entity to play a sound from its position.
+/// The sound fades with the distance to the [`SoundListener`], pans to the side it comes from
+/// and is muffled when a collider blocks the line to the listener.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SpatialSound {
+    pub source: Handle<AudioSource>,
+    pub bus: AudioBus,
+    /// Volume relative to the bus volume
+    pub volume: f64,
+    /// Distance up to which the sound plays at full volume
+    pub near: f32,
+    /// Distance from which the sound is silent
+    pub far: f32,
+    /// Volume multiplier while the line to the listener is blocked.
+    /// Kira filters are not exposed per instance, so the occlusion muffles by volume instead of a low pass.
+    pub occlusion: f64,
+    pub looped: bool,
+    instance: Option<Handle<AudioInstance>>,
+    /// Smoothed occlusion from 0 to 1, so the sound does not pop behind thin colliders
+    occluded: f64,
+}
+impl SpatialSound {
+    /// Creates a looped ambience sound
+    pub fn new(source: Handle<AudioSource>) -> Self {
+        SpatialSound { source, bus: AudioBus::Ambience, volume: 1.0, near: 1.0, far: 20.0, occlusion: 0.3, looped: true, instance: None, occluded: 0.0 }
+    }
+    /// Sets the bus the sound plays on
+    pub fn bus(mut self, bus: AudioBus) -> Self {
+        self.bus = bus;
+        self
+    }
+    /// Sets the volume relative to the bus volume
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = volume;
+        self
+    }
+    /// Sets the distances of full volume and silence
+    pub fn range(mut self, near: f32, far: f32) -> Self {
+        self.near = near;
+        self.far = far.max(near);
+        self
+    }
+    /// Plays the sound only once
+    pub fn once(mut self) -> Self {
+        self.looped = false;
+        self
+    }
+}
+
+
+// #======================#
+// #=== SPATIAL MIXING ===#
+
+/// How much the sound pans to the side it comes from, 1 is hard left and right
+const SPATIAL_PAN_WIDTH: f32 = 0.8;
+/// How fast the occlusion follows the blocked line per second
+const SPATIAL_OCCLUSION_SPEED: f64 = 6.0;
+
+/// Returns the distance attenuation between the near and far distance
+fn spatial_attenuation(sound: &SpatialSound, distance: f32) -> f64 {
+    let fade = ((distance - sound.near) / (sound.far - sound.near).max(0.001)).clamp(0.0, 1.0);
+    ((1.0 - fade) * (1.0 - fade)) as f64
+}
+
+/// System that starts the spatial sounds, sets their volume and panning and stops them when despawned
+#[allow(clippy::too_many_arguments)]
+fn spatial_sound_system(
+    time: Res<Time>,
+    audio: AudioBuses,
+    spatial: SpatialQuery,
+    mut instances: ResMut<Assets<AudioInstance>>,
+    mut playing: Local<HashMap<Entity, Handle<AudioInstance>>>,
+    listener: Query<(Entity, &GlobalTransform), With<SoundListener>>,
+    parents: Query<&Parent>,
+    mut query: Query<(Entity, &GlobalTransform, &mut SpatialSound)>,
+) {
+    // Stop the sounds of despawned emitters
+    playing.retain(|entity, instance| {
+        let alive = query.contains(*entity);
+        if !alive { if let Some(instance) = instances.get_mut(instance) { instance.stop(AudioTween::default()); } }
+        alive
+    });
+
+    let listener = listener.get_single().ok();
+    for (entity, transform, mut sound) in &mut query {
+        // Start the sound silent, the volume is set below
+        if sound.instance.is_none() {
+            let mut command = audio.play_with_volume(sound.bus, sound.source.clone(), 0.0);
+            if sound.looped { command.looped(); }
+            let instance = command.handle();
+            playing.insert(entity, instance.clone());
+            sound.instance = Some(instance);
+        }
+
+        // Without a listener the sound is heard from nowhere
+        let (volume, panning) = match listener {
+            Some((listener, listener_transform)) => {
+                let offset = transform.translation() - listener_transform.translation();
+                let distance = offset.length();
+
+                // Check if anything but the listener, the emitter and their parents blocks the line
+                let blocked = Dir3::new(offset).ok().is_some_and(|direction| {
+                    let excluded = [listener, entity].into_iter().chain(parents.iter_ancestors(listener)).chain(parents.iter_ancestors(entity));
+                    spatial.cast_ray(listener_transform.translation(), direction, distance, true, SpatialQueryFilter::from_excluded_entities(excluded)).is_some()
+                });
+                let target = if blocked { 1.0 } else { 0.0 };
+                sound.occluded += (target - sound.occluded) * (1.0 - (-SPATIAL_OCCLUSION_SPEED * time.delta_seconds_f64()).exp());
+
+                // Pan by the side of the listener the sound is on
+                let local = listener_transform.affine().inverse().transform_vector3(offset).normalize_or_zero();
+                let panning = 0.5 + (local.x * SPATIAL_PAN_WIDTH * 0.5) as f64;
+
+                (spatial_attenuation(&sound, distance) * (1.0 + (sound.occlusion - 1.0) * sound.occluded), panning)
+            },
+            None => (0.0, 0.5),
+        };
+
+        if let Some(instance) = sound.instance.as_ref().and_then(|instance| instances.get_mut(instance)) {
+            let tween = AudioTween::linear(Duration::from_millis(50));
+            // The bus volume is applied by the channel
+            instance.set_volume(volume * sound.volume, tween.clone());
+            instance.set_panning(panning, tween);
+        }
+    }
+}
+
+
+// #============================#
+// #=== SPATIAL SOUND PLUGIN ===#
+
+/// Plugin adding the spatial sounds
+pub struct SpatialSoundPlugin;
+impl Plugin for SpatialSoundPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PostUpdate, spatial_sound_system.after(TransformSystem::TransformPropagate));
+    }
+}
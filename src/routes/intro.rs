@@ -1,3 +1,4 @@
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
 use crate::*;
 
 
@@ -9,15 +10,125 @@ use crate::*;
 pub struct IntroRoute;
 
 
+// #====================#
+// #=== INTRO ASSET ===#
+
+/// What is displayed on the slide
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntroContent {
+    Image(Handle<Image>),
+    /// Path to an animated gif, only played on native builds
+    Gif(String),
+    /// Localization key of the text
+    Text(String),
+}
+
+/// Music cue played when the slide starts
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntroMusic {
+    Play { source: Handle<AudioSource>, volume: f64 },
+    Stop,
+}
+
+/// Single slide of the intro
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntroSlide {
+    pub content: IntroContent,
+    /// How long the slide is displayed in seconds. Gifs with zero duration play until their last frame.
+    pub duration: f32,
+    /// Length of the fade in and fade out in seconds
+    pub fade: f32,
+    pub music: Option<IntroMusic>,
+}
+
+/// Intro sequence loaded from a `.slides` file
+#[derive(Asset, TypePath, Debug, Default, Clone)]
+pub struct IntroSequence {
+    pub slides: Vec<IntroSlide>,
+}
+
+/// Asset loader for the intro sequence. The file is a list of `[[slide]]` tables with `key = value` pairs:
+/// `image`, `gif` or `text` for the content, `duration`, `fade`, `music` (path or `"stop"`) and `volume`.
+#[derive(Default)]
+struct IntroSequenceLoader;
+impl AssetLoader for IntroSequenceLoader {
+    type Asset = IntroSequence;
+    type Settings = ();
+    type Error = std::io::Error;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), load_context: &'a mut LoadContext<'_>) -> Result<IntroSequence, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = std::str::from_utf8(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        // Collect the key value pairs of every slide
+        let mut tables: Vec<Vec<(String, String)>> = Vec::new();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            if line == "[[slide]]" { tables.push(Vec::new()); continue; }
+            let (Some(table), Some((key, value))) = (tables.last_mut(), line.split_once('=')) else { continue };
+            table.push((key.trim().to_string(), value.trim().trim_matches('"').to_string()));
+        }
+
+        let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+        let mut slides = Vec::new();
+        for (index, table) in tables.into_iter().enumerate() {
+            let get = |key: &str| table.iter().find(|(k, _)| k == key).map(|(_, value)| value.clone());
+            let number = |key: &str, default: f32| get(key).map(|value| value.parse::<f32>().map_err(|_| invalid(format!("Slide {index} has invalid {key} \"{value}\"")))).unwrap_or(Ok(default));
+
+            let content = if let Some(path) = get("image") {
+                IntroContent::Image(load_context.load(path))
+            } else if let Some(path) = get("gif") {
+                IntroContent::Gif(path)
+            } else if let Some(key) = get("text") {
+                IntroContent::Text(key)
+            } else {
+                return Err(invalid(format!("Slide {index} has no image, gif or text")));
+            };
+
+            let music = match get("music") {
+                Some(path) if path == "stop" => Some(IntroMusic::Stop),
+                Some(path) => Some(IntroMusic::Play { source: load_context.load(path), volume: number("volume", 1.0)? as f64 }),
+                None => None,
+            };
+
+            slides.push(IntroSlide { content, duration: number("duration", 2.5)?, fade: number("fade", 0.5)?, music });
+        }
+        Ok(IntroSequence { slides })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["slides"]
+    }
+}
+
+
 // #===============================#
 // #=== SANDBOXED USER INTEFACE ===#
 
-#[derive(Component, Debug, Default, Clone, PartialEq)]
-struct IntroGif;
+/// Playback state of the intro
+#[derive(Component, Debug, Clone, PartialEq)]
+struct IntroPlayer {
+    sequence: Handle<IntroSequence>,
+    /// Index of the current slide, none until the sequence is loaded
+    index: Option<usize>,
+    /// Time since the current slide started
+    time: f32,
+    /// How long any input has been held
+    held: f32,
+    /// The ui tree entity, gifs are spawned into it
+    tree: Entity,
+    image: Entity,
+    text: Entity,
+    gif: Option<Entity>,
+}
+
+/// How long any input has to be held to skip the whole intro
+const INTRO_HOLD_TO_SKIP: f32 = 1.0;
+/// Length of the fade out when a slide is skipped
+const INTRO_SKIP_FADE: f32 = 0.2;
 
 /// System that builds the route
-#[cfg(not(target_family = "wasm"))]
-fn build_route(mut commands: Commands, assets: Res<AssetServer>, preloader: Res<PreLoader>, query: Query<Entity, Added<IntroRoute>>, mut event: EventWriter<actions::HideCursor2d>) {
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<IntroRoute>>, mut event: EventWriter<actions::HideCursor2d>) {
     for route_entity in &query {
         // #======================#
         // #=== USER INTERFACE ===#
@@ -25,16 +136,20 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, preloader: Res<
         // Hide cursor
         event.send(actions::HideCursor2d(true));
 
+        let mut player = None;
+
         // Spawn route
         commands.entity(route_entity).insert(
             SpatialBundle::default(),
         ).with_children(|route| {
 
             // Spawn the master ui tree
-            route.spawn((
+            let mut tree = route.spawn((
                 UiTreeBundle::<MainUi>::from(UiTree::new2d("Intro")),
                 SourceFromCamera,
-            )).with_children(|ui| {
+            ));
+            let tree_entity = tree.id();
+            tree.with_children(|ui| {
 
                 // Spawn the root div
                 let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
@@ -50,25 +165,56 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, preloader: Res<
                     UiImage2dBundle::from(assets.load(PreLoader::INTRO_BACKGROUND)),  // We use this bundle to add background image to our node
                 ));
 
-                // Spawn the intro
-                ui.spawn((
-                    root.add("Intro"), // You can see here that we used existing "root" link to create chained link (same as "Root/Intro")
+                // Spawn the slide image
+                let image = ui.spawn((
+                    root.add("Slide"),
                     UiLayout::solid().size((1920.0, 1080.0)).pack::<Base>(),
                     UiDepthBias(1.0), // "background" and this node are on the same level, they will have same depth. Add this to avoid Z fighting.
-                    
-                    Element::default(),
-                    Dimension::default(),
+                    UiImage2dBundle::default(),
+                )).insert(Visibility::Hidden).id();
 
-                    // Spawn the gif bundle
-                    AnimatedImageBundle {
-                        animated_image: preloader.intro.clone(),
+                // Spawn the slide text
+                let text = ui.spawn((
+                    root.add("Text"),
+                    UiLayout::window().pos(Rl((50., 50.))).anchor(Anchor::Center).pack::<Base>(),
+                    UiDepthBias(2.0),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED.with_alpha(0.0),
+                            }).with_justify(JustifyText::Center),
+                        ..default()
+                    },
+                    LocalizedText::default(),
+                    UiTextSize::new().size(Rh(4.0)),
+                )).id();
+
+                // Spawn the skip hint
+                ui.spawn((
+                    root.add("Hint"),
+                    UiLayout::window().pos(Rl((97., 95.))).anchor(Anchor::BottomRight).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_LIGHT),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED_DIM,
+                            }),
                         ..default()
                     },
-                    IntroGif,
+                    LocalizedText::new("intro-skip"),
+                    UiTextSize::new().size(Rh(2.5)),
                 ));
 
+                player = Some(IntroPlayer { sequence: assets.load(PreLoader::INTRO_SEQUENCE), index: None, time: 0.0, held: 0.0, tree: tree_entity, image, text, gif: None });
             });
         });
+
+        if let Some(player) = player {
+            commands.entity(route_entity).insert(player);
+        }
     }
 }
 
@@ -76,35 +222,171 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, preloader: Res<
 // #=====================#
 // #=== INTERACTIVITY ===#
 
-/// Function that checks if our main intro has finished playing
-#[cfg(not(target_family = "wasm"))]
-fn despawn_intro_and_spawn_main_menu(
+/// Returns true if any key, mouse button or gamepad button is pressed
+fn any_pressed(keyboard: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>, gamepad: &ButtonInput<GamepadButton>) -> bool {
+    keyboard.get_pressed().next().is_some() || mouse.get_pressed().next().is_some() || gamepad.get_pressed().next().is_some()
+}
+
+/// Returns true if any key, mouse button or gamepad button was just pressed
+fn any_just_pressed(keyboard: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>, gamepad: &ButtonInput<GamepadButton>) -> bool {
+    keyboard.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some() || gamepad.get_just_pressed().next().is_some()
+}
+
+/// System that plays the slides, skips them on input and changes to main menu at the end
+#[allow(clippy::too_many_arguments)]
+fn intro_play_system(
     mut commands: Commands,
-    mut event: EventWriter<actions::HideCursor2d>,
-    route: Query<Entity, With<IntroRoute>>,
-    intro: Query<&AnimatedImageController, With<IntroGif>>,
+    time: Res<Time>,
     assets: Res<AssetServer>,
-    audio: Res<Audio>,
+    sequences: Res<Assets<IntroSequence>>,
+    mut music: EventWriter<PlayMusic>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepad: Res<ButtonInput<GamepadButton>>,
+    console: Res<Console>,
+    mut event: EventWriter<actions::HideCursor2d>,
+    mut query: Query<(Entity, &mut IntroPlayer)>,
+    mut images: Query<(&mut Handle<Image>, &mut Sprite, &mut Visibility)>,
+    mut texts: Query<(&mut LocalizedText, &mut Text)>,
+    #[cfg(not(target_family = "wasm"))] gifs: Query<&AnimatedImageController>,
 ) {
-    for gif in &intro {
-        if gif.current_frame() == 0 {
-            // Play audio
-            audio.play(assets.load(PreLoader::MUSIC_INTRO)).with_volume(0.5);
+    // Keys typed into the console don't skip the intro
+    let no_keys = ButtonInput::default();
+    let keyboard = if console.open { &no_keys } else { &*keyboard };
+
+    for (route, mut player) in &mut query {
+
+        // Wait for the sequence and all of its images, a broken sequence skips the intro
+        let loaded = assets.is_loaded_with_dependencies(&player.sequence);
+        let failed = matches!(assets.get_load_state(&player.sequence), Some(bevy::asset::LoadState::Failed(_)))
+            || matches!(assets.get_recursive_dependency_load_state(&player.sequence), Some(bevy::asset::RecursiveDependencyLoadState::Failed));
+        if !loaded && !failed { continue; }
+        let slides = sequences.get(&player.sequence).map(|sequence| sequence.slides.as_slice()).unwrap_or_default();
+
+        // Skip the slide on press, skip everything on hold
+        let mut finished = slides.is_empty();
+        player.held = if any_pressed(keyboard, &mouse, &gamepad) { player.held + time.delta_seconds() } else { 0.0 };
+        if player.held >= INTRO_HOLD_TO_SKIP { finished = true; }
+
+        let mut index = player.index.unwrap_or(0);
+        if let Some(slide) = slides.get(index) {
+            if player.index.is_some() {
+                player.time += time.delta_seconds();
+                if any_just_pressed(keyboard, &mouse, &gamepad) {
+                    player.time = player.time.max(slide_duration(slide) - INTRO_SKIP_FADE.min(slide.fade));
+                }
+            }
+
+            // Gifs without duration end on their last frame
+            #[cfg(not(target_family = "wasm"))]
+            if let (IntroContent::Gif(_), true, Some(Ok(gif))) = (&slide.content, slide.duration <= 0.0, player.gif.map(|gif| gifs.get(gif))) {
+                if gif.current_frame() + 1 == gif.frame_count() { player.time = player.time.max(slide_duration(slide)); }
+            }
+
+            // Move to the next slide
+            if player.index.is_none() || player.time >= slide_duration(slide) {
+                if player.index.is_some() { index += 1; }
+                player.time = 0.0;
+                match slides.get(index) {
+                    Some(next) => start_slide(&mut commands, &assets, &mut music, &mut player, next, &mut images, &mut texts),
+                    None => finished = true,
+                }
+                player.index = Some(index);
+            }
         }
-        if gif.current_frame() + 1 == gif.frame_count() {
 
+        if finished {
             // Unhide cursor
             event.send(actions::HideCursor2d(false));
 
-            // Change to main menu
-            commands.entity(route.single()).despawn_recursive();
-            commands.spawn(MainMenuRoute);
+            // Change to main menu, its playlist crossfades from the intro music
+            commands.entity(route).despawn_recursive();
+            commands.spawn(LoadingRoute::new(AppRoute::MainMenu));
+            continue;
+        }
+
+        // Fade the slide in and out
+        if let Some(slide) = slides.get(index) {
+            let alpha = if slide.fade > 0.0 { (player.time / slide.fade).min((slide_duration(slide) - player.time) / slide.fade).clamp(0.0, 1.0) } else { 1.0 };
+            if let Ok((_, mut sprite, _)) = images.get_mut(player.image) { sprite.color.set_alpha(alpha); }
+            if let Ok((_, mut text)) = texts.get_mut(player.text) { text.sections[0].style.color.set_alpha(alpha); }
+        }
+    }
+}
+
+/// Returns the duration of the slide, gifs without duration are faded out by their last frame
+fn slide_duration(slide: &IntroSlide) -> f32 {
+    if slide.duration > 0.0 { slide.duration } else { f32::MAX }
+}
+
+/// Shows the content of the slide and plays its music cue
+fn start_slide(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    music: &mut EventWriter<PlayMusic>,
+    player: &mut IntroPlayer,
+    slide: &IntroSlide,
+    images: &mut Query<(&mut Handle<Image>, &mut Sprite, &mut Visibility)>,
+    texts: &mut Query<(&mut LocalizedText, &mut Text)>,
+) {
+    // Remove the gif of the previous slide
+    if let Some(gif) = player.gif.take() {
+        commands.entity(gif).despawn_recursive();
+    }
 
-            // Play music
-            audio.stop();
-            audio.play(assets.load(PreLoader::MUSIC)).looped();
+    // Show the image
+    if let Ok((mut handle, _, mut visibility)) = images.get_mut(player.image) {
+        match &slide.content {
+            IntroContent::Image(image) => {
+                *handle = image.clone();
+                *visibility = Visibility::Inherited;
+            },
+            _ => *visibility = Visibility::Hidden,
         }
     }
+
+    // Show the text
+    if let Ok((mut localized, _)) = texts.get_mut(player.text) {
+        let key = match &slide.content { IntroContent::Text(key) => key.clone(), _ => String::new() };
+        if localized.key != key { localized.key = key; }
+    }
+
+    // Spawn the gif
+    match &slide.content {
+        #[cfg(not(target_family = "wasm"))]
+        IntroContent::Gif(path) => {
+            let mut gif = None;
+            commands.entity(player.tree).with_children(|ui| {
+                gif = Some(ui.spawn((
+                    UiLink::<MainUi>::path("Root/Gif"),
+                    UiLayout::solid().size((1920.0, 1080.0)).pack::<Base>(),
+                    UiDepthBias(1.0),
+                    Element::default(),
+                    Dimension::default(),
+
+                    // Spawn the gif bundle
+                    AnimatedImageBundle {
+                        animated_image: assets.load(path.clone()),
+                        ..default()
+                    },
+                )).id());
+            });
+            player.gif = gif;
+        },
+        #[cfg(target_family = "wasm")]
+        IntroContent::Gif(path) => {
+            // Gifs are not supported on the web, the slide is shown empty
+            warn!("Skipping intro gif \"{path}\" on the web");
+        },
+        _ => {},
+    }
+
+    // Play the music cue
+    match &slide.music {
+        Some(IntroMusic::Play { source, volume }) => { music.send(PlayMusic(MusicPlaylist::track(source.clone()).volume(*volume).once())); },
+        Some(IntroMusic::Stop) => { music.send(PlayMusic(MusicPlaylist::silence())); },
+        None => {},
+    }
 }
 
 
@@ -116,11 +398,13 @@ pub struct IntroRoutePlugin;
 impl Plugin for IntroRoutePlugin {
     fn build(&self, app: &mut App) {
         #[cfg(not(target_family = "wasm"))]
+        app.add_plugins(AnimatedImagePlugin);
+
         app
-            .add_plugins(AnimatedImagePlugin)
+            .init_asset::<IntroSequence>()
+            .init_asset_loader::<IntroSequenceLoader>()
 
-            .add_systems(Update, despawn_intro_and_spawn_main_menu)
+            .add_systems(Update, intro_play_system.before(UiSystems::Compute))
             .add_systems(PreUpdate, build_route.before(UiSystems::Compute));
     }
 }
-
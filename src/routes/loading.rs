@@ -0,0 +1,451 @@
+use bevy::asset::{LoadState, LoadedUntypedAsset, RecursiveDependencyLoadState};
+use bevy::utils::HashMap;
+use rand::Rng;
+use crate::*;
+
+
+// #=========================#
+// #=== EXPOSED COMPONENT ===#
+
+/// Routes that can be navigated to through the [`LoadingRoute`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppRoute {
+    #[default]
+    Intro,
+    MainMenu,
+    CharacterCreator,
+    Game,
+    Settings,
+    Credits,
+    AdditionalContent,
+}
+impl AppRoute {
+    /// Every route of the game
+    pub const ALL: [AppRoute; 7] = [AppRoute::Intro, AppRoute::MainMenu, AppRoute::CharacterCreator, AppRoute::Game, AppRoute::Settings, AppRoute::Credits, AppRoute::AdditionalContent];
+    /// Name of the route used on the command line and in the console
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppRoute::Intro => "intro",
+            AppRoute::MainMenu => "main-menu",
+            AppRoute::CharacterCreator => "character-creator",
+            AppRoute::Game => "game",
+            AppRoute::Settings => "settings",
+            AppRoute::Credits => "credits",
+            AppRoute::AdditionalContent => "additional-content",
+        }
+    }
+    /// Returns the route with the given name
+    pub fn from_name(name: &str) -> Option<Self> {
+        AppRoute::ALL.into_iter().find(|route| route.name() == name)
+    }
+    /// Assets the route needs before it is built
+    pub fn manifest(&self) -> Vec<String> {
+        let paths: Vec<String> = match self {
+            AppRoute::Intro => vec![PreLoader::INTRO_BACKGROUND.into(), PreLoader::INTRO_SEQUENCE.into(), PreLoader::MUSIC_INTRO.into()],
+            AppRoute::MainMenu => vec![PreLoader::MAIN_BACKGROUND.into(), PreLoader::MAIN_BOARD.into(), PreLoader::MAIN_LOGO.into(), PreLoader::MUSIC.into(), PreLoader::PARTICLES_DUST.into(), PreLoader::PARTICLES_NEON_SPARKS.into()],
+            AppRoute::CharacterCreator => {
+                let mut paths = vec![PreLoader::SETTINGS_BACKGROUND.into(), PreLoader::CHARACTER_CREATOR_PANEL.into(), PreLoader::MUSIC.into(), PreLoader::STINGER_CONFIRM.into()];
+                for gender in PreLoader::CHARACTER_GENDERS {
+                    for body in PreLoader::CHARACTER_BODIES {
+                        paths.push(PreLoader::character_model(gender, body));
+                    }
+                }
+                paths
+            },
+            AppRoute::Game => {
+                let mut paths = vec![PreLoader::SKYBOX.into(), PreLoader::SKYBOX_DIFFUSE.into(), PreLoader::SKYBOX_SPECULAR.into(), PreLoader::HUD.into(), PreLoader::MUSIC_INTRO.into(), PreLoader::MUSIC.into(), PreLoader::PARTICLES_RAIN.into(), PreLoader::PARTICLES_SPARKS.into(), PreLoader::PARTICLES_STEAM.into(), PreLoader::SFX_NEON_BUZZ.into(), PreLoader::SFX_TERMINAL.into(), PreLoader::SFX_CITY.into()];
+                for surface in SurfaceTag::ALL {
+                    paths.extend(surface.footsteps().into_iter().chain([surface.landing()]).map(String::from));
+                }
+                paths
+            },
+            AppRoute::Settings | AppRoute::AdditionalContent => vec![PreLoader::SETTINGS_BACKGROUND.into()],
+            AppRoute::Credits => vec![PreLoader::SETTINGS_BACKGROUND.into(), PreLoader::CREDITS.into()],
+        };
+        PreLoader::MANIFEST_COMMON.iter().map(|path| path.to_string()).chain(paths).collect()
+    }
+    /// Spawns the route
+    pub fn spawn(&self, commands: &mut Commands) {
+        match self {
+            AppRoute::Intro => commands.spawn(IntroRoute),
+            AppRoute::MainMenu => commands.spawn(MainMenuRoute),
+            AppRoute::CharacterCreator => commands.spawn(CharacterCreatorRoute),
+            AppRoute::Game => commands.spawn(GameRoute),
+            AppRoute::Settings => commands.spawn(SettingsRoute),
+            AppRoute::Credits => commands.spawn(CreditsRoute),
+            AppRoute::AdditionalContent => commands.spawn(AdditionalContentRoute),
+        };
+    }
+}
+
+/// When this component is added, a UI system is built. It loads the manifest of the target route
+/// and replaces itself with the target route once everything is loaded.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct LoadingRoute {
+    pub target: AppRoute,
+}
+impl LoadingRoute {
+    pub fn new(target: AppRoute) -> Self {
+        LoadingRoute { target }
+    }
+}
+
+/// Resource tracking the built routes, so a route can return to the one it was opened from
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct RouteHistory {
+    /// The route that is built now
+    pub current: Option<AppRoute>,
+    /// The route that was built before the current one
+    pub previous: Option<AppRoute>,
+}
+
+/// Resource keeping the manifest assets of the current route alive, so routes sharing them never load them again.
+/// The common assets stay pinned for the whole run.
+#[derive(Resource, Debug, Default)]
+pub struct AssetCache {
+    handles: HashMap<String, Handle<LoadedUntypedAsset>>,
+}
+impl AssetCache {
+    /// Returns the handle of the path, starting the load if it is not cached yet
+    fn load(&mut self, path: &str, assets: &AssetServer) -> Handle<LoadedUntypedAsset> {
+        self.handles.entry(path.to_string()).or_insert_with(|| assets.load_untyped(path.to_string())).clone()
+    }
+    /// Drops the handles that the manifest does not share, except the common assets
+    fn retain(&mut self, manifest: &[String]) {
+        self.handles.retain(|path, _| manifest.contains(path) || PreLoader::MANIFEST_COMMON.contains(&path.as_str()));
+    }
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Loading progress of the route
+#[derive(Component, Debug, Clone, PartialEq)]
+struct LoadingState {
+    handles: Vec<(String, Handle<LoadedUntypedAsset>)>,
+    time: f32,
+    tip: usize,
+    /// Ui tree that is shown once loading takes long enough to notice
+    tree: Entity,
+    fill: Entity,
+    progress: Entity,
+    tip_text: Entity,
+    /// Nodes of the error screen
+    error: Vec<Entity>,
+    error_list: Entity,
+}
+
+/// Loading screen is only shown if loading takes longer than this, to avoid flashing it
+const LOADING_SHOW_DELAY: f32 = 0.2;
+/// How long every tip is shown
+const LOADING_TIP_TIME: f32 = 5.0;
+/// Localization keys of the tips
+const LOADING_TIPS: [&str; 4] = ["loading-tip-1", "loading-tip-2", "loading-tip-3", "loading-tip-4"];
+
+/// System that builds the route
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, mut cache: ResMut<AssetCache>, mut rng: ResMut<GameRng>, query: Query<(Entity, &LoadingRoute), Added<LoadingRoute>>) {
+    for (route_entity, loading) in &query {
+        // #======================#
+        // #=== USER INTERFACE ===#
+
+        let manifest = loading.target.manifest();
+        cache.retain(&manifest);
+        let handles = manifest.into_iter().map(|path| { let handle = cache.load(&path, &assets); (path, handle) }).collect();
+        let tip = rng.0.gen_range(0..LOADING_TIPS.len());
+        let mut state = None;
+
+        // Spawn the route
+        commands.entity(route_entity).insert(
+            SpatialBundle::default(),
+        ).with_children(|route| {
+
+            // Spawn the master ui tree, hidden until loading takes long enough
+            let mut tree = route.spawn((
+                UiTreeBundle::<MainUi>::from(UiTree::new2d("Loading")),
+                SourceFromCamera,
+            ));
+            tree.insert(Visibility::Hidden);
+            let tree_entity = tree.id();
+            tree.with_children(|ui| {
+
+                // Spawn the root div
+                let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
+                ui.spawn((
+                    root.clone(),                           // Here we add the link
+                    UiLayout::window_full().pack::<Base>(),         // This is where we define layout
+                ));
+
+                // Spawn the background
+                ui.spawn((
+                    root.add("Background"), // You can see here that we used existing "root" link to create chained link (same as "Root/Background")
+                    UiLayout::solid().size((2968.0, 1656.0)).scaling(Scaling::Fill).pack::<Base>(),
+                    UiImage2dBundle::from(assets.load(PreLoader::SETTINGS_BACKGROUND)),  // We use this bundle to add background image to our node
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the progress bar
+                let bar = root.add("Bar");
+                ui.spawn((
+                    bar.clone(),
+                    UiLayout::window().pos(Rl((20.0, 80.0))).size(Rl((60.0, 2.0))).pack::<Base>(),
+                    UiImage2dBundle {
+                        sprite: Sprite { color: Color::BEVYPUNK_RED.with_alpha(0.15), ..default() },
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                ));
+                let fill = ui.spawn((
+                    bar.add("Fill"),
+                    UiLayout::window().size(Rl((0.0, 100.0))).pack::<Base>(),
+                    UiImage2dBundle {
+                        sprite: Sprite { color: Color::BEVYPUNK_YELLOW.with_alpha(1.2), ..default() },
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                )).id();
+
+                // Spawn the progress text
+                let progress = ui.spawn((
+                    root.add("Progress"),
+                    UiLayout::window().pos(Rl((80.0, 77.0))).anchor(Anchor::BottomRight).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_MEDIUM),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    UiTextSize::new().size(Rh(3.0)),
+                    Pickable::IGNORE,
+                )).id();
+
+                // Spawn the loading title
+                ui.spawn((
+                    root.add("Title"),
+                    UiLayout::window().pos(Rl((20.0, 77.0))).anchor(Anchor::BottomLeft).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_SEMIBOLD),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new("loading-title"),
+                    UiTextSize::new().size(Rh(3.0)),
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the tip
+                let tip_text = ui.spawn((
+                    root.add("Tip"),
+                    UiLayout::window().pos(Rl((50.0, 86.0))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED_DIM,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new(LOADING_TIPS[tip]),
+                    UiTextSize::new().size(Rh(2.5)),
+                    Pickable::IGNORE,
+                )).id();
+
+                // Spawn the error screen, it is shown if any asset fails to load
+                let error = root.add("Error");
+                let mut error_nodes = Vec::new();
+                error_nodes.push(ui.spawn((
+                    error.clone(),
+                    UiLayout::window_full().pack::<Base>(),
+                    UiImage2dBundle {
+                        sprite: Sprite { color: Color::BLACK.with_alpha(0.85), ..default() },
+                        ..default()
+                    },
+                    UiDepthBias(10.0),
+                    PickableBundle::default(),
+                )).id());
+
+                error_nodes.push(ui.spawn((
+                    error.add("Title"),
+                    UiLayout::window().pos(Rl((50.0, 20.0))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_SEMIBOLD),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_YELLOW.with_alpha(1.2),
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new("loading-error-title"),
+                    UiTextSize::new().size(Rh(5.0)),
+                    Pickable::IGNORE,
+                )).id());
+
+                let error_list = ui.spawn((
+                    error.add("List"),
+                    UiLayout::window().pos(Rl((50.0, 32.0))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }).with_justify(JustifyText::Center),
+                        ..default()
+                    },
+                    UiTextSize::new().size(Rh(2.5)),
+                    Pickable::IGNORE,
+                )).id();
+                error_nodes.push(error_list);
+
+                // Spawn the error buttons
+                let target = loading.target;
+                error_nodes.push(ui.spawn((
+                    error.add("Continue"),
+                    UiLayout::window().pos(Rl((31.0, 80.0))).size(Rl((18.0, 7.0))).pack::<Base>(),
+                    Button { text: "loading-continue".into(), ..default() },
+
+                    // Continue to the route without the missing assets
+                    OnUiClickDespawn::new(route_entity),
+                    OnUiClickCommands::new(move |commands| { target.spawn(commands); }),
+                )).id());
+                error_nodes.push(ui.spawn((
+                    error.add("Return"),
+                    UiLayout::window().pos(Rl((51.0, 80.0))).size(Rl((18.0, 7.0))).pack::<Base>(),
+                    Button { text: "common-return-main-menu".into(), ..default() },
+                    WidgetSounds::back(),
+
+                    // Return to the main menu, loading its manifest like any other route
+                    OnUiClickDespawn::new(route_entity),
+                    OnUiClickCommands::new(|commands| { commands.spawn(LoadingRoute::new(AppRoute::MainMenu)); }),
+                )).id());
+
+                state = Some(LoadingState { handles, time: 0.0, tip, tree: tree_entity, fill, progress, tip_text, error: error_nodes, error_list });
+            });
+        });
+
+        if let Some(state) = state {
+            // The error screen is hidden until something fails
+            for entity in &state.error { commands.entity(*entity).insert(Visibility::Hidden); }
+            commands.entity(route_entity).insert(state);
+        }
+    }
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that tracks the loading progress and navigates once everything is loaded
+fn loading_progress_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<AssetServer>,
+    mut query: Query<(Entity, &LoadingRoute, &mut LoadingState)>,
+    mut localized: Query<&mut LocalizedText>,
+    mut texts: Query<&mut Text, Without<LocalizedText>>,
+) {
+    for (route, loading, mut state) in &mut query {
+        if state.time < 0.0 { continue; }
+        state.time += time.delta_seconds();
+
+        // Sort the handles by their state
+        let mut loaded = 0;
+        let mut failed = Vec::new();
+        for (path, handle) in &state.handles {
+            let root = assets.get_load_state(handle);
+            let dependencies = assets.get_recursive_dependency_load_state(handle);
+            if matches!(root, Some(LoadState::Failed(_))) || dependencies == Some(RecursiveDependencyLoadState::Failed) {
+                failed.push(path.clone());
+            } else if dependencies == Some(RecursiveDependencyLoadState::Loaded) {
+                loaded += 1;
+            }
+        }
+        let total = state.handles.len();
+
+        // Navigate once everything is loaded
+        if loaded == total {
+            commands.entity(route).despawn_recursive();
+            loading.target.spawn(&mut commands);
+            continue;
+        }
+
+        // Show the error screen once everything else settled
+        if !failed.is_empty() && loaded + failed.len() == total {
+            for path in &failed { error!("Failed to load \"{path}\""); }
+            if let Ok(mut text) = texts.get_mut(state.error_list) { text.sections[0].value = failed.join("\n"); }
+            for entity in &state.error { commands.entity(*entity).insert(Visibility::Inherited); }
+            commands.entity(state.tree).insert(Visibility::Inherited);
+            state.time = -1.0;
+            continue;
+        }
+
+        // Show the loading screen only if loading takes a while
+        if state.time >= LOADING_SHOW_DELAY {
+            commands.entity(state.tree).insert(Visibility::Inherited);
+        }
+
+        // Update the progress
+        let progress = if total > 0 { (loaded + failed.len()) as f32 / total as f32 } else { 1.0 };
+        commands.entity(state.fill).insert(UiLayout::window().size(Rl((progress * 100.0, 100.0))).pack::<Base>());
+        if let Ok(mut text) = texts.get_mut(state.progress) {
+            let value = format!("{:.0}%", progress * 100.0);
+            if text.sections[0].value != value { text.sections[0].value = value; }
+        }
+
+        // Rotate the tips
+        let tip = (state.tip + (state.time / LOADING_TIP_TIME) as usize) % LOADING_TIPS.len();
+        if let Ok(mut text) = localized.get_mut(state.tip_text) {
+            if text.key != LOADING_TIPS[tip] { text.key = LOADING_TIPS[tip].into(); }
+        }
+    }
+}
+
+
+/// System that records the routes as they are built
+#[allow(clippy::type_complexity)]
+fn route_history_system(mut history: ResMut<RouteHistory>, query: Query<(Has<IntroRoute>, Has<MainMenuRoute>, Has<CharacterCreatorRoute>, Has<GameRoute>, Has<SettingsRoute>, Has<CreditsRoute>, Has<AdditionalContentRoute>), Or<(
+    Added<IntroRoute>, Added<MainMenuRoute>, Added<CharacterCreatorRoute>, Added<GameRoute>,
+    Added<SettingsRoute>, Added<CreditsRoute>, Added<AdditionalContentRoute>,
+)>>) {
+    for flags in &query {
+        let route = match flags {
+            (true, ..) => AppRoute::Intro,
+            (_, true, ..) => AppRoute::MainMenu,
+            (_, _, true, ..) => AppRoute::CharacterCreator,
+            (_, _, _, true, ..) => AppRoute::Game,
+            (_, _, _, _, true, ..) => AppRoute::Settings,
+            (_, _, _, _, _, true, _) => AppRoute::Credits,
+            _ => AppRoute::AdditionalContent,
+        };
+        if history.current != Some(route) {
+            history.previous = history.current;
+            history.current = Some(route);
+        }
+    }
+}
+
+
+// #====================#
+// #=== ROUTE PLUGIN ===#
+
+/// Plugin adding all our logic
+pub struct LoadingRoutePlugin;
+impl Plugin for LoadingRoutePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<AssetCache>()
+            .init_resource::<RouteHistory>()
+            .add_systems(PreUpdate, build_route.before(UiSystems::Compute))
+            .add_systems(PreUpdate, route_history_system)
+            .add_systems(Update, loading_progress_system.before(UiSystems::Compute));
+    }
+}
@@ -18,6 +18,9 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
         // #======================#
         // #=== USER INTERFACE ===#
 
+        // Play the menu music
+        commands.entity(route_entity).insert(MusicPlaylist::track(assets.load(PreLoader::MUSIC)));
+
         // Spawn the route
         commands.entity(route_entity).insert(
             SpatialBundle::default(),
@@ -44,6 +47,17 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                     UiImage2dBundle::from(assets.load(PreLoader::MAIN_BACKGROUND)),  // We use this bundle to add background image to our node
                 ));
 
+                // Spawn the ambient particles between the background and the board
+                for (name, effect) in [("Dust", PreLoader::PARTICLES_DUST), ("Sparks", PreLoader::PARTICLES_NEON_SPARKS)] {
+                    ui.spawn((
+                        root.add(name),
+                        UiLayout::window_full().pack::<Base>(),
+                        UiDepthBias(0.5),
+                        Dimension::default(),   // The particles spawn over the whole node
+                        ParticleEmitter::new2d(assets.load(effect)),
+                    ));
+                }
+
 
                 // Spawn the board
                 let board = root.add("Solid");
@@ -75,55 +89,29 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
                 // #=========================#
                 // #=== MAIN MENU BUTTONS ===#
 
-                // Spawn button boundary
-                let list = board.add("List");
+                // Spawn the button list, the buttons are built by the list
                 ui.spawn((
-                    list.clone(),
-                    UiLayout::window().pos(Rl((22.0, 33.0))).size(Rl((55.0, 34.0))).pack::<Base>(),
-                ));
-
-                // Spawn buttons
-                let gap = 3.0;
-                let size = 14.0;
-                let mut offset = 0.0;
-                for button in [MainMenuButton::Continue, MainMenuButton::NewGame, MainMenuButton::LoadGame, MainMenuButton::Settings, MainMenuButton::AdditionalContent, MainMenuButton::Credits, MainMenuButton::QuitGame] {
-
-                    let mut btn = ui.spawn((
-                        // Link the entity
-                        list.add(button.str()),
-
-                        // Add the button type
-                        button.clone(),
-
-                        // Add layout
-                        UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack::<Base>(),
-
-                        // Add the button component
-                        MainButton { text: button.str().into() },
-                    ));
-
-                    // Insert specific components if the condition is true
-                    if button == MainMenuButton::NewGame {
-                        btn.insert((
-                            // Despawn this entity on UiClick
-                            OnUiClickDespawn::new(route_entity),
-
-                            // Run this command on UiClick
-                            OnUiClickCommands::new(|commands| { commands.spawn(CharacterCreatorRoute); })
-                        ));
-                    }
-                    if button == MainMenuButton::Continue {
-                        btn.insert((
-                            // Despawn this entity on UiClick
-                            OnUiClickDespawn::new(route_entity),
-
-                            // Run this command on UiClick
-                            OnUiClickCommands::new(|commands| { commands.spawn(GameRoute); })
+                    board.add("List"),
+                    UiLayout::window().pos(Rl((22.0, 33.0))).size(Rl((55.0, 39.5))).pack::<Base>(),
+                    ScrollList::new(MAIN_MENU_BUTTONS.len(), 12.0, |row, index| {
+                        let button = MAIN_MENU_BUTTONS[index].clone();
+
+                        // Rows are recycled, so every index inserts the same components and the clicks are handled by the button type
+                        row.insert((
+                            // Add the button component, there are no saves to load yet
+                            MainButton { text: button.key().into(), disabled: button == MainMenuButton::LoadGame, ..default() },
+
+                            // Add the button type
+                            button.clone(),
                         ));
-                    }
 
-                    offset += gap + size;
-                }
+                        // Only the disabled load button explains itself
+                        match button.tooltip() {
+                            Some(tooltip) => { row.insert(Tooltip::new(tooltip)); },
+                            None => { row.remove::<Tooltip>(); },
+                        }
+                    }).gap(2.6),
+                ));
             });
         });
     }
@@ -133,6 +121,9 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
 // #=====================#
 // #=== INTERACTIVITY ===#
 
+/// Buttons of the main menu in the order they are listed
+const MAIN_MENU_BUTTONS: [MainMenuButton; 7] = [MainMenuButton::Continue, MainMenuButton::NewGame, MainMenuButton::LoadGame, MainMenuButton::Settings, MainMenuButton::AdditionalContent, MainMenuButton::Credits, MainMenuButton::QuitGame];
+
 /// Good practice is to use custom component for buttons, so we can easily know what type of button was pressed
 #[derive(Component, Clone, PartialEq)]
 enum MainMenuButton {
@@ -145,36 +136,60 @@ enum MainMenuButton {
     QuitGame,
 }
 impl MainMenuButton {
-    fn str(&self) -> String {
+    /// Localization key of the button label
+    fn key(&self) -> &'static str {
         match self {
-            MainMenuButton::Continue => "CONTINUE".into(),
-            MainMenuButton::NewGame => "NEW GAME".into(),
-            MainMenuButton::LoadGame => "LOAD GAME".into(),
-            MainMenuButton::Settings => "FULLSCREEN".into(),
-            MainMenuButton::AdditionalContent => "ADDITIONAL CONTENT".into(),
-            MainMenuButton::Credits => "CREDITS".into(),
-            MainMenuButton::QuitGame => "QUIT GAME".into(),
+            MainMenuButton::Continue => "main-menu-continue",
+            MainMenuButton::NewGame => "main-menu-new-game",
+            MainMenuButton::LoadGame => "main-menu-load-game",
+            MainMenuButton::Settings => "main-menu-settings",
+            MainMenuButton::AdditionalContent => "main-menu-additional-content",
+            MainMenuButton::Credits => "main-menu-credits",
+            MainMenuButton::QuitGame => "main-menu-quit-game",
+        }
+    }
+    /// Localization key of the button tooltip
+    fn tooltip(&self) -> Option<&'static str> {
+        match self {
+            MainMenuButton::LoadGame => Some("main-menu-load-game-tooltip"),
+            _ => None,
+        }
+    }
+    /// Route the button opens
+    fn route(&self) -> Option<AppRoute> {
+        match self {
+            MainMenuButton::Continue => Some(AppRoute::Game),
+            MainMenuButton::NewGame => Some(AppRoute::CharacterCreator),
+            MainMenuButton::Settings => Some(AppRoute::Settings),
+            MainMenuButton::AdditionalContent => Some(AppRoute::AdditionalContent),
+            MainMenuButton::Credits => Some(AppRoute::Credits),
+            MainMenuButton::LoadGame | MainMenuButton::QuitGame => None,
         }
     }
 }
 
 /// In this system we run our button click logic
-fn main_menu_button_clicked_system(mut events: EventReader<UiClickEvent>, query: Query<&MainMenuButton, With<MainButton>>, mut exit: EventWriter<bevy::app::AppExit>,
-    mut event1: EventWriter<actions::SetWindowMode>,
-    mut event2: EventWriter<actions::SetWindowResolution>
-) {
+fn main_menu_button_clicked_system(mut commands: Commands, mut events: EventReader<UiClickEvent>, query: Query<&MainMenuButton, With<MainButton>>, route: Query<Entity, With<MainMenuRoute>>) {
     for event in events.read() {
         if let Ok(button) = query.get(event.target) {
-            info!("Pressed: {}", button.str());
+            info!("Pressed: {}", button.key());
+
+            // Leave the menu for the route of the button
+            if let Some(next) = button.route() {
+                for route in &route { commands.entity(route).despawn_recursive(); }
+                commands.spawn(LoadingRoute::new(next));
+                continue;
+            }
 
             // Here we can run code on button click
             match button {
                 MainMenuButton::QuitGame => {
-                    exit.send(bevy::app::AppExit::Success);
-                },
-                MainMenuButton::Settings => {
-                    event1.send(actions::SetWindowMode(bevy::window::WindowMode::BorderlessFullscreen));
-                    event2.send(actions::SetWindowResolution(Vec2::new(1920.0, 1080.0)));
+                    commands.spawn((
+                        Dialog::confirm("dialog-quit-title", "dialog-quit-message"),
+                        OnDialogResult::new(DialogChoice::Confirm, |commands| {
+                            commands.add(|world: &mut World| { world.send_event(bevy::app::AppExit::Success); });
+                        }),
+                    ));
                 },
                 _ => {},
             }
@@ -192,7 +207,7 @@ impl Plugin for MainMenuRoutePlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(PreUpdate, build_route.before(UiSystems::Compute))
-            .add_systems(Update, main_menu_button_clicked_system.run_if(on_event::<UiClickEvent>()));
+            .add_systems(Update, main_menu_button_clicked_system.run_if(on_event::<UiClickEvent>()).run_if(no_dialog_open));
     }
 }
 
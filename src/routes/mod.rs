@@ -1,12 +1,21 @@
 pub mod game;
 pub use game::*;
 
+pub mod additional_content;
+pub use additional_content::*;
+
 pub mod character_creator;
 pub use character_creator::*;
 
+pub mod credits;
+pub use credits::*;
+
 pub mod intro;
 pub use intro::*;
 
+pub mod loading;
+pub use loading::*;
+
 pub mod main_menu;
 pub use main_menu::*;
 
@@ -25,8 +34,11 @@ impl Plugin for RoutePlugin {
     fn build(&self, app: &mut App) {
         app
             .add_plugins(GamePlugin)
+            .add_plugins(AdditionalContentRoutePlugin)
             .add_plugins(CharacterCreatorRoutePlugin)
+            .add_plugins(CreditsRoutePlugin)
             .add_plugins(IntroRoutePlugin)
+            .add_plugins(LoadingRoutePlugin)
             .add_plugins(MainMenuRoutePlugin)
             .add_plugins(SettingsRoutePlugin);
     }
@@ -13,30 +13,157 @@ pub struct SettingsRoute;
 // #=== SANDBOXED USER INTEFACE ===#
 
 /// System that builds the route
-fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<SettingsRoute>>) {
-    for entity in &query {
+fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<Entity, Added<SettingsRoute>>, localization: Res<Localization>, volumes: Res<VolumeSettings>, motion: Res<MotionSettings>, effects: Res<ScreenEffectSettings>, window: Query<&Window, With<bevy::window::PrimaryWindow>>) {
+    for route_entity in &query {
         // #======================#
         // #=== USER INTERFACE ===#
 
-        // Spawn the master ui tree
-        commands.entity(entity).insert((
-            UiTreeBundle::<MainUi>::from(UiTree::new2d("Settings")),
-        )).with_children(|ui| {
-
-            // Spawn the root div
-            let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
-            ui.spawn((
-                root.clone(),                           // Here we add the link
-                UiLayout::window_full().pack::<Base>(),         // This is where we define layout
-            ));
-
-            // Spawn the background
-            ui.spawn((
-                root.add("Background"), // You can see here that we used existing "root" link to create chained link (same as "Root/Background")
-                UiLayout::solid().size((2968.0, 1656.0)).scaling(Scaling::Fill).pack::<Base>(),
-                UiImage2dBundle::from(assets.load(PreLoader::SETTINGS_BACKGROUND)),  // We use this bundle to add background image to our node
-            ));
+        // Pick the spinner indexes matching the current settings
+        let language = LANGUAGES.iter().position(|language| *language == localization.language).unwrap_or(0);
+        let display = window.get_single().map(|window| (window.mode != bevy::window::WindowMode::Windowed) as usize).unwrap_or(0);
+        let reduced_motion = motion.reduced_motion as usize;
+        let screen_effects = SCREEN_EFFECT_STRENGTHS.iter().position(|(_, strength)| *strength == effects.strength).unwrap_or(2);
 
+        // Spawn the route
+        commands.entity(route_entity).insert(
+            SpatialBundle::default(),
+        ).with_children(|route| {
+
+            // Spawn the master ui tree
+            route.spawn((
+                UiTreeBundle::<MainUi>::from(UiTree::new2d("Settings")),
+                SourceFromCamera,
+            )).with_children(|ui| {
+
+                // Spawn the root div
+                let root = UiLink::<MainUi>::path("Root");  // Here we can define the name of the node
+                ui.spawn((
+                    root.clone(),                           // Here we add the link
+                    UiLayout::window_full().pack::<Base>(),         // This is where we define layout
+                ));
+
+                // Spawn the background
+                ui.spawn((
+                    root.add("Background"), // You can see here that we used existing "root" link to create chained link (same as "Root/Background")
+                    UiLayout::solid().size((2968.0, 1656.0)).scaling(Scaling::Fill).pack::<Base>(),
+                    UiImage2dBundle::from(assets.load(PreLoader::SETTINGS_BACKGROUND)),  // We use this bundle to add background image to our node
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn return button
+                ui.spawn((
+                    root.add("Return"),
+                    UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack::<Base>(),
+                    Button { text: "common-return-main-menu".into(), ..default() },
+                    WidgetSounds::back(),
+
+                    // If it detects UiClick event for this entity it will despawn route_entity and run a closure
+                    OnUiClickDespawn::new(route_entity),
+                    OnUiClickCommands::new(|commands| { commands.spawn(MainMenuRoute); })
+                ));
+
+                // Spawn title
+                ui.spawn((
+                    root.add("Title"),
+                    UiLayout::window().pos(Rl((50., 8.))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_SEMIBOLD),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new("settings-title"),
+                    TextEffect::decode(40.0),
+                    UiTextSize::new().size(Rh(5.0)),
+                ));
+
+                // Spawn the settings list, the spinners are built by the list
+                let settings = vec![
+                    (SettingsSpinner::Language, "settings-language", LANGUAGES.iter().map(|language| SpinnerOption::new(*language, format!("language-{language}"))).collect::<Vec<_>>(), language),
+                    (SettingsSpinner::Display, "settings-display", vec![SpinnerOption::new("windowed", "settings-display-windowed"), SpinnerOption::new("fullscreen", "settings-display-fullscreen")], display),
+                    (SettingsSpinner::ScreenEffects, "settings-screen-effects", SCREEN_EFFECT_STRENGTHS.iter().map(|(id, _)| SpinnerOption::new(*id, format!("settings-screen-effects-{id}"))).collect(), screen_effects),
+                    (SettingsSpinner::ReducedMotion, "settings-reduced-motion", vec![SpinnerOption::new("off", "settings-off"), SpinnerOption::new("on", "settings-on")], reduced_motion),
+                ];
+                ui.spawn((
+                    root.add("List"),
+                    UiLayout::window().pos(Rl((30.0, 20.0))).anchor(Anchor::TopCenter).size(Rl((30.0, 60.0))).pack::<Base>(),
+                    ScrollList::new(settings.len(), 14.0, move |row, index| {
+                        let (setting, name, options, index) = settings[index].clone();
+                        row.insert((
+                            Spinner::new(name, options).with_index(index),
+                            Tooltip::new(format!("{name}-tooltip")),
+                            setting,
+                        ));
+                    }),
+                ));
+
+                // Spawn the audio header
+                ui.spawn((
+                    root.add("Audio"),
+                    UiLayout::window().pos(Rl((70.0, 16.0))).anchor(Anchor::Center).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_MEDIUM),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::new("settings-audio"),
+                    UiTextSize::new().size(Rh(3.0)),
+                ));
+
+                // Spawn the volume sliders and mute switches, the master bus is the first row
+                let buses: Vec<(Option<AudioBus>, BusVolume)> = std::iter::once(None).chain(AudioBus::ALL.map(Some)).map(|bus| (bus, volumes.bus(bus))).collect();
+                let switches = buses.clone();
+                ui.spawn((
+                    root.add("Volumes"),
+                    UiLayout::window().pos(Rl((55.0, 20.0))).size(Rl((24.0, 60.0))).pack::<Base>(),
+                    ScrollList::new(buses.len(), 11.0, move |row, index| {
+                        let (bus, volume) = buses[index];
+                        let name = bus.map(|bus| bus.name()).unwrap_or("master");
+                        row.insert((
+                            Slider::new(format!("settings-volume-{name}"), 0.0, 100.0).step(5.0).with_value(volume.volume * 100.0),
+                            Tooltip::new("settings-volume-tooltip"),
+                            SettingsVolume(bus),
+                        ));
+                    }),
+                ));
+                ui.spawn((
+                    root.add("Mutes"),
+                    UiLayout::window().pos(Rl((80.0, 20.0))).size(Rl((10.0, 60.0))).pack::<Base>(),
+                    ScrollList::new(switches.len(), 11.0, move |row, index| {
+                        let (bus, volume) = switches[index];
+                        row.insert((
+                            Switch { text: "settings-mute".into(), on: volume.muted, ..default() },
+                            Tooltip::new("settings-mute-tooltip"),
+                            SettingsVolume(bus),
+                        ));
+                    }),
+                ));
+
+                // Spawn the description of the focused setting
+                ui.spawn((
+                    root.add("Description"),
+                    UiLayout::window().pos(Rl((50., 85.))).anchor(Anchor::TopCenter).pack::<Base>(),
+                    UiText2dBundle {
+                        text: Text::from_section("",
+                            TextStyle {
+                                font: assets.load(PreLoader::FONT_REGULAR),
+                                font_size: 60.0,
+                                color: Color::BEVYPUNK_RED,
+                            }),
+                        ..default()
+                    },
+                    LocalizedText::default(),
+                    TooltipPanel { placeholder: "settings-description-placeholder".into() },
+                    UiTextSize::new().size(Rh(3.0)),
+                ));
+            });
         });
     }
 }
@@ -45,6 +172,70 @@ fn build_route(mut commands: Commands, assets: Res<AssetServer>, query: Query<En
 // #=====================#
 // #=== INTERACTIVITY ===#
 
+/// Good practice is to use custom component for spinners, so we can easily know which setting was changed
+#[derive(Component, Clone, PartialEq)]
+enum SettingsSpinner {
+    Language,
+    Display,
+    ScreenEffects,
+    ReducedMotion,
+}
+
+/// Marks the volume slider and mute switch of the audio bus, none is the master bus
+#[derive(Component, Clone, PartialEq)]
+struct SettingsVolume(Option<AudioBus>);
+
+/// Options of the screen effects setting and the strength they set
+const SCREEN_EFFECT_STRENGTHS: [(&str, f32); 3] = [("off", 0.0), ("subtle", 0.5), ("full", 1.0)];
+
+/// In this system we apply the changed settings
+fn settings_spinner_changed_system(mut events: EventReader<SpinnerChangeEvent>, query: Query<&SettingsSpinner, With<Spinner>>,
+    mut language: EventWriter<SetLanguage>,
+    mut event1: EventWriter<actions::SetWindowMode>,
+    mut event2: EventWriter<actions::SetWindowResolution>,
+    mut motion: ResMut<MotionSettings>,
+    mut effects: ResMut<ScreenEffectSettings>,
+) {
+    for event in events.read() {
+        if let Ok(setting) = query.get(event.target) {
+            match setting {
+                SettingsSpinner::Language => {
+                    language.send(SetLanguage(event.id.clone()));
+                },
+                SettingsSpinner::Display => {
+                    if event.id == "fullscreen" {
+                        event1.send(actions::SetWindowMode(bevy::window::WindowMode::BorderlessFullscreen));
+                        event2.send(actions::SetWindowResolution(Vec2::new(1920.0, 1080.0)));
+                    } else {
+                        event1.send(actions::SetWindowMode(bevy::window::WindowMode::Windowed));
+                        event2.send(actions::SetWindowResolution(Vec2::new(1280.0, 720.0)));
+                    }
+                },
+                SettingsSpinner::ScreenEffects => {
+                    effects.strength = SCREEN_EFFECT_STRENGTHS.iter().find(|(id, _)| *id == event.id).map(|(_, strength)| *strength).unwrap_or(1.0);
+                },
+                SettingsSpinner::ReducedMotion => {
+                    motion.reduced_motion = event.id == "on";
+                },
+            }
+        }
+    }
+}
+
+/// In this system we apply the changed volumes
+fn settings_volume_changed_system(mut sliders: EventReader<SliderChangeEvent>, mut switches: EventReader<SwitchChangeEvent>, query: Query<&SettingsVolume>, mut volumes: ResMut<VolumeSettings>) {
+    for event in sliders.read() {
+        if let Ok(SettingsVolume(bus)) = query.get(event.target) {
+            volumes.bus_mut(*bus).volume = event.value / 100.0;
+        }
+    }
+    for event in switches.read() {
+        if let Ok(SettingsVolume(bus)) = query.get(event.target) {
+            volumes.bus_mut(*bus).muted = event.on;
+        }
+    }
+}
+
 /// Event for changing game settings.
 #[derive(Event)]
 pub struct SetCameraHdr (pub bool);
@@ -66,6 +257,8 @@ impl Plugin for SettingsRoutePlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(PreUpdate, build_route.before(UiSystems::Compute))
+            .add_systems(Update, settings_spinner_changed_system.run_if(on_event::<SpinnerChangeEvent>()))
+            .add_systems(Update, settings_volume_changed_system.run_if(on_event::<SliderChangeEvent>().or_else(on_event::<SwitchChangeEvent>())))
 
             // Add events that change the app settings
             .add_event::<SetCameraHdr>()
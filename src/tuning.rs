@@ -0,0 +1,459 @@
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::ecs::event::ManualEventReader;
+use bevy::reflect::{GetPath, GetTypeRegistration, ReflectRef};
+#[cfg(not(target_family = "wasm"))]
+use bevy::asset::io::{file::FileAssetReader, AssetSource};
+use crate::*;
+
+
+// #=========================#
+// #=== TUNABLES REGISTRY ===#
+
+/// Numeric field of a reflected resource that is editable in the tuning panel and saved to the tuning file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tunable {
+    /// Short type path of the resource, filled in on registration
+    pub resource: String,
+    pub field: String,
+    /// Localization key of the slider label
+    pub text: String,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+impl Tunable {
+    pub fn new(field: impl Into<String>, text: impl Into<String>, min: f32, max: f32) -> Self {
+        Tunable { resource: String::new(), field: field.into(), text: text.into(), min, max, step: 0.0 }
+    }
+    /// Sets the snapping step of the slider
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// Resource with all registered tunables in the order they were registered
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Tunables {
+    pub list: Vec<Tunable>,
+}
+
+/// Extension trait for registering tunables from plugins
+pub trait TunableAppExt {
+    /// Initializes the resource, registers it for reflection and adds the fields to the tuning panel
+    fn register_tunables<R: Resource + Default + Reflect + TypePath + GetTypeRegistration>(&mut self, tunables: Vec<Tunable>) -> &mut Self;
+}
+impl TunableAppExt for App {
+    fn register_tunables<R: Resource + Default + Reflect + TypePath + GetTypeRegistration>(&mut self, tunables: Vec<Tunable>) -> &mut Self {
+        self.init_resource::<R>().register_type::<R>();
+        let resource = R::short_type_path();
+        self.world_mut().get_resource_or_insert_with(Tunables::default).list.extend(
+            tunables.into_iter().map(|tunable| Tunable { resource: resource.to_string(), ..tunable })
+        );
+        self
+    }
+}
+
+
+// #==========================#
+// #=== REFLECTION HELPERS ===#
+
+/// Finds the reflected resource by its short type path
+fn reflect_resource(world: &World, resource: &str) -> Result<ReflectResource, String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    registry.get_with_short_type_path(resource).and_then(|registration| registration.data::<ReflectResource>()).cloned()
+        .ok_or_else(|| format!("Unknown resource \"{resource}\""))
+}
+
+/// Returns the field of a reflected resource, an empty field returns the whole resource
+pub fn reflect_get<'w>(world: &'w World, resource: &str, field: &str) -> Result<&'w dyn Reflect, String> {
+    let value = reflect_resource(world, resource)?.reflect(world).ok_or_else(|| format!("Resource \"{resource}\" does not exist"))?;
+    if field.is_empty() { return Ok(value); }
+    value.reflect_path(format!(".{field}").as_str()).map_err(|error| error.to_string())
+}
+
+/// Parses the value into the field of a reflected resource, an empty field targets the whole resource
+pub fn reflect_set(world: &mut World, resource: &str, field: &str, value: &str) -> Result<(), String> {
+    let reflect = reflect_resource(world, resource)?;
+    let mut target = reflect.reflect_mut(world).ok_or_else(|| format!("Resource \"{resource}\" does not exist"))?;
+    let target: &mut dyn Reflect = if field.is_empty() { &mut *target } else { (*target).reflect_path_mut(format!(".{field}").as_str()).map_err(|error| error.to_string())? };
+
+    let invalid = || format!("Invalid value \"{value}\"");
+    if let Some(target) = target.downcast_mut::<f32>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<f64>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<bool>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<i32>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<u32>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<usize>() { *target = value.parse().map_err(|_| invalid())?; }
+    else if let Some(target) = target.downcast_mut::<String>() { *target = value.to_string(); }
+    else { return Err(format!("Can't write a value of type {}", target.reflect_short_type_path())); }
+    Ok(())
+}
+
+/// Returns the dotted paths of all struct fields of the reflected value, nested fields included
+pub fn reflect_field_paths(value: &dyn Reflect) -> Vec<String> {
+    let ReflectRef::Struct(value) = value.reflect_ref() else { return Vec::new() };
+    let mut paths = Vec::new();
+    for index in 0..value.field_len() {
+        let (Some(name), Some(field)) = (value.name_at(index), value.field_at(index)) else { continue };
+        paths.push(name.to_string());
+        paths.extend(reflect_field_paths(field).into_iter().map(|path| format!("{name}.{path}")));
+    }
+    paths
+}
+
+/// Reads the reflected number as f32
+pub fn reflect_f32(value: &dyn Reflect) -> Option<f32> {
+    if let Some(value) = value.downcast_ref::<f32>() { Some(*value) }
+    else if let Some(value) = value.downcast_ref::<f64>() { Some(*value as f32) }
+    else if let Some(value) = value.downcast_ref::<i32>() { Some(*value as f32) }
+    else if let Some(value) = value.downcast_ref::<u32>() { Some(*value as f32) }
+    else if let Some(value) = value.downcast_ref::<usize>() { Some(*value as f32) }
+    else { None }
+}
+
+
+// #====================#
+// #=== TUNING ASSET ===#
+
+/// Name of the asset source reading straight from the assets folder, so the tuning can be hot-reloaded
+#[cfg(not(target_family = "wasm"))]
+const TUNING_SOURCE: &str = "tuning";
+
+/// Comment written at the top of the saved tuning file
+const TUNING_HEADER: &str = "# Gameplay tuning. Edit it here or in the tuning panel (F2), changes are hot-reloaded.\n";
+
+/// Tuning values loaded from a `.tuning` file
+#[derive(Asset, TypePath, Debug, Default, Clone)]
+pub struct Tuning {
+    /// Resource, field and value of every line
+    pub values: Vec<(String, String, String)>,
+}
+impl Tuning {
+    /// Parses `[Resource]` sections with `field = value` lines. Lines starting with `#` are comments.
+    pub fn parse(source: &str) -> Self {
+        let mut tuning = Tuning::default();
+        let mut section = String::new();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                section = name.trim().to_string();
+            } else if let Some((field, value)) = line.split_once('=') {
+                tuning.values.push((section.clone(), field.trim().to_string(), value.trim().to_string()));
+            }
+        }
+        tuning
+    }
+    /// Writes the current values of all tunables in the tuning file format
+    pub fn serialize(world: &World) -> String {
+        let mut source = String::from(TUNING_HEADER);
+        let mut section = "";
+        for tunable in &world.resource::<Tunables>().list {
+            let Some(value) = reflect_get(world, &tunable.resource, &tunable.field).ok().and_then(reflect_f32) else { continue };
+            if tunable.resource != section {
+                section = &tunable.resource;
+                source += &format!("\n[{section}]\n");
+            }
+            source += &format!("{} = {value}\n", tunable.field);
+        }
+        source
+    }
+}
+
+/// Asset loader for `.tuning` files
+#[derive(Default)]
+struct TuningLoader;
+impl AssetLoader for TuningLoader {
+    type Asset = Tuning;
+    type Settings = ();
+    type Error = std::io::Error;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), _load_context: &'a mut LoadContext<'_>) -> Result<Tuning, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = std::str::from_utf8(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Tuning::parse(source))
+    }
+    fn extensions(&self) -> &[&str] {
+        &["tuning"]
+    }
+}
+
+/// Path to the tuning file in the assets folder next to the game
+#[cfg(not(target_family = "wasm"))]
+fn tuning_file() -> std::path::PathBuf {
+    FileAssetReader::get_base_path().join("assets").join(PreLoader::TUNING)
+}
+
+/// Writes the current tuning to the tuning file and returns its path
+pub fn save_tuning(world: &World) -> Result<String, String> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let path = tuning_file();
+        std::fs::write(&path, Tuning::serialize(world)).map_err(|error| format!("Failed to save the tuning: {error}"))?;
+        Ok(path.display().to_string())
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = world;
+        Err("Saving the tuning is not supported on the web".into())
+    }
+}
+
+/// Handle to the tuning file and whether it needs to be applied
+#[derive(Resource, Debug, Clone)]
+struct TuningState {
+    handle: Handle<Tuning>,
+    reload: bool,
+}
+impl FromWorld for TuningState {
+    fn from_world(world: &mut World) -> Self {
+        // Read the file from disk if it exists, so changes to it are picked up by the watcher
+        #[cfg(not(target_family = "wasm"))]
+        let path = if tuning_file().is_file() { format!("{TUNING_SOURCE}://{}", PreLoader::TUNING) } else { PreLoader::TUNING.to_string() };
+        #[cfg(target_family = "wasm")]
+        let path = PreLoader::TUNING.to_string();
+        TuningState { handle: world.resource::<AssetServer>().load(path), reload: false }
+    }
+}
+
+/// System that marks the tuning for applying when it is loaded or modified
+fn tuning_reload_system(mut events: EventReader<AssetEvent<Tuning>>, mut state: ResMut<TuningState>) {
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } if *id == state.handle.id() => state.reload = true,
+            _ => {},
+        }
+    }
+}
+
+/// System that writes the loaded tuning into the resources
+fn tuning_apply_system(world: &mut World) {
+    if !world.resource::<TuningState>().reload { return; }
+    world.resource_mut::<TuningState>().reload = false;
+
+    let handle = world.resource::<TuningState>().handle.clone();
+    let Some(tuning) = world.resource::<Assets<Tuning>>().get(&handle).cloned() else { return };
+    for (resource, field, value) in &tuning.values {
+        if let Err(error) = reflect_set(world, resource, field, value) {
+            warn!("Invalid tuning {resource}.{field}: {error}");
+        }
+    }
+    info!("Applied {} tuning values", tuning.values.len());
+}
+
+
+// #===============================#
+// #=== SANDBOXED USER INTEFACE ===#
+
+/// Resource controlling the tuning panel
+#[derive(Resource, Debug, Default, Clone)]
+pub struct TuningPanel {
+    pub open: bool,
+    /// Set by the save button, the save needs the world
+    save: bool,
+}
+
+/// Marker of the tuning panel overlay entity
+#[derive(Component, Debug, Clone, PartialEq)]
+struct TuningOverlay;
+
+/// Row of the tuning list, pointing into the [`Tunables`] list
+#[derive(Component, Debug, Clone, PartialEq)]
+struct TunableRow {
+    index: usize,
+}
+
+/// Marker of the save button
+#[derive(Component, Debug, Clone, PartialEq)]
+struct TuningSaveButton;
+
+/// Spawns the tuning panel overlay
+fn spawn_panel(commands: &mut Commands, assets: &AssetServer, tunables: &Tunables) {
+    let tunables = tunables.list.clone();
+    commands.spawn((SpatialBundle::default(), TuningOverlay)).with_children(|route| {
+
+        // Spawn the master ui tree
+        route.spawn((
+            UiTreeBundle::<MainUi>::from(UiTree::new2d("Tuning")),
+            SourceFromCamera,
+        )).with_children(|ui| {
+
+            // Spawn the root div
+            let root = UiLink::<MainUi>::path("Root");
+            ui.spawn((
+                root.clone(),
+                UiLayout::window_full().pack::<Base>(),
+
+                // Render the panel above the routes, but below the console
+                UiDepthBias(90.0),
+            ));
+
+            // Spawn the panel blocking the input for the route beneath
+            let panel = root.add("Panel");
+            ui.spawn((
+                panel.clone(),
+                UiLayout::window().pos(Rl((62.0, 5.0))).size(Rl((36.0, 90.0))).pack::<Base>(),
+                UiImage2dBundle {
+                    sprite: Sprite { color: Color::BLACK.with_alpha(0.85), ..default() },
+                    ..default()
+                },
+                PickableBundle::default(),
+            ));
+
+            // Spawn the title
+            ui.spawn((
+                panel.add("Title"),
+                UiLayout::window().pos(Rl((5.0, 3.0))).anchor(Anchor::TopLeft).pack::<Base>(),
+                UiText2dBundle {
+                    text: Text::from_section("",
+                        TextStyle {
+                            font: assets.load(PreLoader::FONT_SEMIBOLD),
+                            font_size: 60.0,
+                            color: Color::BEVYPUNK_RED,
+                        }),
+                    ..default()
+                },
+                LocalizedText::new("tuning-title"),
+                UiTextSize::new().size(Rh(5.0)),
+                Pickable::IGNORE,
+            ));
+
+            // Spawn the list, the sliders are built by the list
+            ui.spawn((
+                panel.add("List"),
+                UiLayout::window().pos(Rl((5.0, 11.0))).size(Rl((90.0, 74.0))).pack::<Base>(),
+                ScrollList::new(tunables.len(), 9.0, move |row, index| {
+                    let tunable = &tunables[index];
+                    row.insert((
+                        Slider::new(&tunable.text, tunable.min, tunable.max).step(tunable.step),
+                        TunableRow { index },
+                    ));
+                }).gap(2.0),
+            ));
+
+            // Spawn the save button
+            ui.spawn((
+                panel.add("Save"),
+                UiLayout::window().pos(Rl((5.0, 88.0))).size(Rl((90.0, 8.0))).pack::<Base>(),
+                Button { text: "tuning-save".into(), ..default() },
+                TuningSaveButton,
+            ));
+        });
+    });
+}
+
+
+// #=====================#
+// #=== INTERACTIVITY ===#
+
+/// System that opens and closes the tuning panel with F2 or when the resource changes
+fn tuning_toggle_system(mut commands: Commands, assets: Res<AssetServer>, keys: Res<ButtonInput<KeyCode>>, console: Res<Console>, tunables: Res<Tunables>, mut panel: ResMut<TuningPanel>, overlays: Query<Entity, With<TuningOverlay>>) {
+    if keys.just_pressed(KeyCode::F2) && !console.open { panel.open = !panel.open; }
+    if panel.open && overlays.is_empty() {
+        spawn_panel(&mut commands, &assets, &tunables);
+    }
+    if !panel.open {
+        for entity in &overlays { commands.entity(entity).despawn_recursive(); }
+    }
+}
+
+/// Returns true if the tuning panel is open
+fn tuning_panel_open(panel: Res<TuningPanel>) -> bool {
+    panel.open
+}
+
+/// System that requests a save when the save button is clicked
+fn tuning_save_button_system(mut events: EventReader<UiClickEvent>, buttons: Query<(), With<TuningSaveButton>>, mut panel: ResMut<TuningPanel>) {
+    for event in events.read() {
+        if buttons.contains(event.target) { panel.save = true; }
+    }
+}
+
+/// System that writes the dragged sliders into the resources, reads the current values back
+/// into the sliders and saves the tuning when requested
+fn tuning_panel_system(world: &mut World, mut changes: Local<ManualEventReader<SliderChangeEvent>>) {
+    let tunables = world.resource::<Tunables>().list.clone();
+
+    // Write the dragged values
+    let events: Vec<SliderChangeEvent> = changes.read(world.resource::<Events<SliderChangeEvent>>()).cloned().collect();
+    for event in events {
+        let Some(tunable) = world.get::<TunableRow>(event.target).and_then(|row| tunables.get(row.index)) else { continue };
+        if let Err(error) = reflect_set(world, &tunable.resource, &tunable.field, &event.value.to_string()) {
+            warn!("Failed to tune {}.{}: {error}", tunable.resource, tunable.field);
+        }
+    }
+
+    // Read the values back, they can also change from the console or the tuning file
+    let rows: Vec<(Entity, usize)> = world.query::<(Entity, &TunableRow)>().iter(world).map(|(entity, row)| (entity, row.index)).collect();
+    for (entity, index) in rows {
+        let Some(tunable) = tunables.get(index) else { continue };
+        let Some(value) = reflect_get(world, &tunable.resource, &tunable.field).ok().and_then(reflect_f32) else { continue };
+        let Some(mut slider) = world.get_mut::<Slider>(entity) else { continue };
+        if slider.value != value { slider.value = value; }
+    }
+
+    // Save the tuning
+    if std::mem::take(&mut world.resource_mut::<TuningPanel>().save) {
+        let output = save_tuning(world).map(|path| format!("Saved the tuning to {path}")).unwrap_or_else(|error| format!("Error: {error}"));
+        world.resource_mut::<Console>().print(output);
+    }
+}
+
+fn command_tuning(world: &mut World, arguments: &[&str]) -> Result<String, String> {
+    match arguments.first().copied() {
+        None => {
+            let mut panel = world.resource_mut::<TuningPanel>();
+            panel.open = !panel.open;
+            Ok(String::new())
+        },
+        Some("save") => save_tuning(world).map(|path| format!("Saved the tuning to {path}")),
+        Some(argument) => Err(format!("Unknown argument \"{argument}\"")),
+    }
+}
+
+fn command_tuning_complete(_world: &World) -> Vec<String> {
+    vec!["save".into()]
+}
+
+
+// #=====================#
+// #=== TUNING PLUGIN ===#
+
+/// Plugin registering the asset source the tuning file is hot-reloaded from.
+/// It has to be added before the `AssetPlugin` (part of the default plugins).
+pub struct TuningSourcePlugin;
+impl Plugin for TuningSourcePlugin {
+    fn build(&self, app: &mut App) {
+        // The file is only watched with the dev feature, release builds read it once
+        #[cfg(all(not(target_family = "wasm"), feature = "dev"))]
+        app.register_asset_source(TUNING_SOURCE, AssetSource::build()
+            .with_reader(|| Box::new(FileAssetReader::new("assets")))
+            .with_watcher(AssetSource::get_default_watcher("assets".into(), std::time::Duration::from_millis(300)))
+        );
+        #[cfg(all(not(target_family = "wasm"), not(feature = "dev")))]
+        app.register_asset_source(TUNING_SOURCE, AssetSource::build().with_reader(|| Box::new(FileAssetReader::new("assets"))));
+        #[cfg(target_family = "wasm")]
+        let _ = app;
+    }
+}
+
+/// Plugin loading the tuning file and adding the tuning panel
+pub struct TuningPlugin;
+impl Plugin for TuningPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<Tuning>()
+            .init_asset_loader::<TuningLoader>()
+            .init_resource::<Tunables>()
+            .init_resource::<TuningState>()
+            .init_resource::<TuningPanel>()
+            .add_console_command("tuning", ConsoleCommand::new("[save]", "Toggles the tuning panel or saves the tuning", command_tuning).complete(command_tuning_complete))
+            .add_systems(Update, (tuning_reload_system, tuning_apply_system).chain())
+            .add_systems(Update, (
+                tuning_toggle_system,
+                tuning_save_button_system.run_if(on_event::<UiClickEvent>()),
+                tuning_panel_system.run_if(tuning_panel_open),
+            ).chain().before(UiSystems::Compute));
+    }
+}
@@ -0,0 +1,298 @@
+use std::path::Path;
+use bevy::render::{render_asset::RenderAssetUsages, texture::{CompressedImageFormats, ImageSampler, ImageType}};
+use bevy::utils::HashSet;
+use crate::*;
+
+
+// #=======================#
+// #=== ASSET INTEGRITY ===#
+
+/// Returns every asset path the game references directly: all route manifests and string tables
+pub fn asset_paths() -> Vec<String> {
+    let mut paths: Vec<String> = AppRoute::ALL.iter().flat_map(|route| route.manifest()).collect();
+    paths.extend(LANGUAGES.iter().map(|language| locale_path(language)));
+    paths.push(PreLoader::TUNING.into());
+    paths.push(PreLoader::SHADER_CRT.into());
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Returns the asset paths referenced from inside of a file, like the intro slides, particle images or credits licenses
+fn references(path: &str, source: &str) -> Vec<String> {
+    match extension(path).as_str() {
+        "slides" => source.lines().filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            let value = value.trim().trim_matches('"');
+            (matches!(key.trim(), "image" | "gif" | "music") && value != "stop").then(|| value.to_string())
+        }).collect(),
+        "particles" => source.lines().filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            (key.trim() == "image").then(|| value.trim().trim_matches('"').to_string())
+        }).collect(),
+        "md" => source.lines().filter_map(|line| line.trim().strip_prefix("!license").map(|path| path.trim().to_string())).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Lowercase extension of the path
+fn extension(path: &str) -> String {
+    Path::new(path).extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default()
+}
+
+/// Checks that the file decodes with the format its extension claims
+fn decode(path: &str, bytes: &[u8]) -> Result<(), String> {
+    match extension(path).as_str() {
+        extension @ ("png" | "jpg" | "jpeg" | "hdr" | "ktx2") => {
+            Image::from_buffer(bytes, ImageType::Extension(extension), CompressedImageFormats::NONE, true, ImageSampler::Default, RenderAssetUsages::default())
+                .map(|_| ()).map_err(|error| error.to_string())
+        },
+        "ttf" | "otf" => Font::try_from_bytes(bytes.to_vec()).map(|_| ()).map_err(|error| error.to_string()),
+        "ogg" => decode_ogg(bytes),
+        "mp3" => decode_mp3(bytes),
+        "wav" => decode_wav(bytes),
+        "glb" => decode_glb(bytes),
+        "gif" => decode_gif(bytes),
+        _ => std::str::from_utf8(bytes).map(|_| ()).map_err(|error| error.to_string()),
+    }
+}
+
+/// Reads a little endian u16 at the offset
+fn u16_le(bytes: &[u8], offset: usize) -> Option<u16> {
+    bytes.get(offset..offset + 2).map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a little endian u32 at the offset
+fn u32_le(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes.get(offset..offset + 4).map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Walks all ogg pages and checks their checksums and the codec of the stream
+fn decode_ogg(bytes: &[u8]) -> Result<(), String> {
+    let mut offset = 0;
+    let mut pages = 0;
+    while offset < bytes.len() {
+        let header = bytes.get(offset..offset + 27).ok_or("truncated page header")?;
+        if &header[0..4] != b"OggS" || header[4] != 0 { return Err(format!("no ogg page at byte {offset}")); }
+        let segments = header[26] as usize;
+        let table = bytes.get(offset + 27..offset + 27 + segments).ok_or("truncated segment table")?;
+        let length = 27 + segments + table.iter().map(|size| *size as usize).sum::<usize>();
+        let page = bytes.get(offset..offset + length).ok_or("truncated page")?;
+
+        // The first packet identifies the codec
+        let packet = &page[27 + segments..];
+        if pages == 0 && !packet.starts_with(b"\x01vorbis") && !packet.starts_with(b"OpusHead") { return Err("not a vorbis or opus stream".into()); }
+        if u32_le(page, 22) != Some(ogg_checksum(page)) { return Err(format!("checksum mismatch in page {pages}")); }
+
+        offset += length;
+        pages += 1;
+    }
+    if pages == 0 { return Err("no ogg pages".into()); }
+    Ok(())
+}
+
+/// Checksum of the ogg page, computed with the checksum field zeroed
+fn ogg_checksum(page: &[u8]) -> u32 {
+    let mut checksum = 0u32;
+    for (index, byte) in page.iter().enumerate() {
+        let byte = if (22..26).contains(&index) { 0 } else { *byte };
+        checksum ^= (byte as u32) << 24;
+        for _ in 0..8 {
+            checksum = if checksum & 0x8000_0000 != 0 { (checksum << 1) ^ 0x04C1_1DB7 } else { checksum << 1 };
+        }
+    }
+    checksum
+}
+
+/// Walks all MPEG layer III frames between the ID3 tags
+fn decode_mp3(bytes: &[u8]) -> Result<(), String> {
+    let mut offset = 0;
+
+    // Skip the ID3v2 tag, its size is stored in 7 bits per byte
+    if bytes.starts_with(b"ID3") {
+        let header = bytes.get(0..10).ok_or("truncated ID3 tag")?;
+        let size = header[6..10].iter().fold(0, |size, byte| (size << 7) | (*byte as usize & 0x7F));
+        let footer = if header[5] & 0x10 != 0 { 10 } else { 0 };
+        offset = 10 + size + footer;
+    }
+
+    // Ignore the ID3v1 tag at the end
+    let end = if bytes.len() >= 128 && bytes[bytes.len() - 128..].starts_with(b"TAG") { bytes.len() - 128 } else { bytes.len() };
+
+    let mut frames = 0;
+    while offset < end {
+        let header = bytes.get(offset..offset + 4).ok_or("truncated frame header")?;
+        offset += mp3_frame_length(header).ok_or_else(|| format!("no layer III frame at byte {offset}"))?;
+        frames += 1;
+    }
+    if offset > end { return Err("truncated last frame".into()); }
+    if frames == 0 { return Err("no mp3 frames".into()); }
+    Ok(())
+}
+
+/// Returns the length of the MPEG layer III frame with the header, including the header
+fn mp3_frame_length(header: &[u8]) -> Option<usize> {
+    const BITRATES_MPEG1: [usize; 15] = [0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320];
+    const BITRATES_MPEG2: [usize; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    const SAMPLE_RATES: [usize; 3] = [44100, 48000, 32000];
+
+    if header[0] != 0xFF || header[1] & 0xE0 != 0xE0 { return None; }
+    let version = (header[1] >> 3) & 0b11;
+    let layer = (header[1] >> 1) & 0b11;
+    let bitrate = (header[2] >> 4) as usize;
+    let sample_rate = ((header[2] >> 2) & 0b11) as usize;
+    let padding = ((header[2] >> 1) & 0b1) as usize;
+    if version == 1 || layer != 1 || bitrate == 0 || bitrate == 15 || sample_rate == 3 { return None; }
+
+    // Version 3 is MPEG 1, 2 is MPEG 2 with half the sample rate and 0 is MPEG 2.5 with a quarter
+    let (bitrate, sample_rate, samples) = match version {
+        3 => (BITRATES_MPEG1[bitrate], SAMPLE_RATES[sample_rate], 144),
+        2 => (BITRATES_MPEG2[bitrate], SAMPLE_RATES[sample_rate] / 2, 72),
+        _ => (BITRATES_MPEG2[bitrate], SAMPLE_RATES[sample_rate] / 4, 72),
+    };
+    Some(samples * bitrate * 1000 / sample_rate + padding)
+}
+
+/// Walks all RIFF chunks and checks the sample format and the data chunk
+fn decode_wav(bytes: &[u8]) -> Result<(), String> {
+    if bytes.get(0..4) != Some(b"RIFF") || bytes.get(8..12) != Some(b"WAVE") { return Err("missing RIFF WAVE header".into()); }
+    let end = u32_le(bytes, 4).ok_or("truncated header")? as usize + 8;
+    if end > bytes.len() { return Err("truncated file".into()); }
+
+    let (mut format, mut data) = (false, false);
+    let mut offset = 12;
+    while offset + 8 <= end {
+        let id = &bytes[offset..offset + 4];
+        let length = u32_le(bytes, offset + 4).unwrap_or_default() as usize;
+        let chunk = bytes.get(offset + 8..offset + 8 + length).ok_or_else(|| format!("truncated \"{}\" chunk", String::from_utf8_lossy(id)))?;
+        match id {
+            b"fmt " => {
+                let (tag, channels, sample_rate) = (u16_le(chunk, 0), u16_le(chunk, 2), u32_le(chunk, 4));
+                if chunk.len() < 16 || !matches!(tag, Some(1 | 3 | 0xFFFE)) || channels == Some(0) || sample_rate == Some(0) { return Err("unsupported sample format".into()); }
+                format = true;
+            },
+            b"data" if !format => return Err("data chunk before the fmt chunk".into()),
+            b"data" => data = true,
+            _ => {},
+        }
+        // Chunks are aligned to 2 bytes
+        offset += 8 + length + length % 2;
+    }
+    if !format { return Err("missing fmt chunk".into()); }
+    if !data { return Err("missing data chunk".into()); }
+    Ok(())
+}
+
+/// Checks the binary glTF header and walks its chunks, the first one must be the JSON
+fn decode_glb(bytes: &[u8]) -> Result<(), String> {
+    if !bytes.starts_with(b"glTF") { return Err("missing glTF header".into()); }
+    if u32_le(bytes, 4) != Some(2) { return Err("unsupported glTF version".into()); }
+    if u32_le(bytes, 8) != Some(bytes.len() as u32) { return Err("length does not match the file size".into()); }
+
+    let mut offset = 12;
+    let mut chunks = 0;
+    while offset < bytes.len() {
+        let length = u32_le(bytes, offset).ok_or("truncated chunk header")? as usize;
+        let kind = bytes.get(offset + 4..offset + 8).ok_or("truncated chunk header")?;
+        let chunk = bytes.get(offset + 8..offset + 8 + length).ok_or("truncated chunk")?;
+        if chunks == 0 {
+            if kind != b"JSON" { return Err("first chunk is not JSON".into()); }
+            let json = std::str::from_utf8(chunk).map_err(|error| error.to_string())?.trim();
+            if !json.starts_with('{') || !json.ends_with('}') { return Err("invalid JSON chunk".into()); }
+        }
+        offset += 8 + length;
+        chunks += 1;
+    }
+    if chunks == 0 { return Err("no glTF chunks".into()); }
+    Ok(())
+}
+
+/// Walks all GIF blocks up to the trailer, at least one frame is required
+fn decode_gif(bytes: &[u8]) -> Result<(), String> {
+    if !bytes.starts_with(b"GIF87a") && !bytes.starts_with(b"GIF89a") { return Err("missing GIF header".into()); }
+    let color_table = |flags: u8| if flags & 0x80 != 0 { 3 << ((flags & 0x07) + 1) } else { 0 };
+
+    let mut offset = 13 + color_table(*bytes.get(10).ok_or("truncated header")?);
+    let mut frames = 0;
+    loop {
+        match bytes.get(offset) {
+            // Extension: label and sub-blocks
+            Some(0x21) => offset = gif_sub_blocks(bytes, offset + 2)?,
+            // Frame: descriptor, optional color table, minimum code size and sub-blocks
+            Some(0x2C) => {
+                let flags = *bytes.get(offset + 9).ok_or("truncated frame descriptor")?;
+                offset = gif_sub_blocks(bytes, offset + 10 + color_table(flags) + 1)?;
+                frames += 1;
+            },
+            Some(0x3B) if frames == 0 => return Err("no frames".into()),
+            Some(0x3B) => return Ok(()),
+            Some(byte) => return Err(format!("unknown block 0x{byte:02X} at byte {offset}")),
+            None => return Err("missing trailer".into()),
+        }
+    }
+}
+
+/// Skips the GIF data sub-blocks starting at the offset and returns the offset after the terminator
+fn gif_sub_blocks(bytes: &[u8], mut offset: usize) -> Result<usize, String> {
+    loop {
+        let size = *bytes.get(offset).ok_or("truncated data block")? as usize;
+        offset += 1 + size;
+        if size == 0 { return Ok(offset); }
+    }
+}
+
+/// Checks every referenced asset in the asset folder and returns all problems found
+pub fn verify_assets(base: &Path) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut visited = HashSet::new();
+    let mut queue = asset_paths();
+
+    while let Some(path) = queue.pop() {
+        if !visited.insert(path.clone()) { continue; }
+        let bytes = match std::fs::read(base.join(&path)) {
+            Ok(bytes) => bytes,
+            Err(error) => { problems.push(format!("{path}: {error}")); continue; },
+        };
+        if let Err(error) = decode(&path, &bytes) {
+            problems.push(format!("{path}: {error}"));
+            continue;
+        }
+        if let Ok(source) = std::str::from_utf8(&bytes) {
+            queue.extend(references(&path, source));
+        }
+    }
+
+    problems.sort();
+    problems
+}
+
+/// Runs the check on the asset folder, prints the report and returns the exit code
+pub fn verify_assets_report() -> i32 {
+    let base = bevy::asset::io::file::FileAssetReader::get_base_path().join("assets");
+    let problems = verify_assets(&base);
+    if problems.is_empty() {
+        println!("All referenced assets are valid");
+        return 0;
+    }
+    eprintln!("Found {} asset problems:", problems.len());
+    for problem in &problems { eprintln!("  {problem}"); }
+    1
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The models, skybox and music are not in git, so the check is skipped unless the asset pack is present
+    #[test]
+    fn referenced_assets_are_valid() {
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+        if !base.join("models").is_dir() || !base.join("scenes").is_dir() {
+            eprintln!("Skipping the asset check, the asset pack is missing in {}", base.display());
+            return;
+        }
+        let problems = verify_assets(&base);
+        assert!(problems.is_empty(), "Found {} asset problems:\n{}", problems.len(), problems.join("\n"));
+    }
+}